@@ -0,0 +1,96 @@
+//! Support for the `Camo` derive macro, which describes, reports and
+//! resolves every field type, without requiring that field types
+//! implement `Camo` or `Descriptor`.
+//!
+//! A `Probe<T>` picks the implementation for `T` if there is one,
+//! and falls back to doing nothing (through auto-ref) otherwise.
+//!
+//! Not public API.
+
+use std::marker::PhantomData;
+
+use crate::ast::{GenericArgument, Item, StructContent, Type, TypeIdentity, TypePath};
+use crate::camo::Camo;
+use crate::descriptor::Descriptor;
+use crate::registry::Dependencies;
+
+pub struct Probe<T: ?Sized>(PhantomData<T>);
+
+impl<T: ?Sized> Probe<T> {
+    #[allow(clippy::new_without_default)]
+    pub fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+pub trait CamoProbe {
+    fn add_to(&self, dependencies: &mut Dependencies);
+
+    fn identity(&self) -> Option<TypeIdentity>;
+
+    fn is_unit_struct(&self) -> bool;
+}
+
+impl<T: Camo> CamoProbe for Probe<T> {
+    fn add_to(&self, dependencies: &mut Dependencies) {
+        dependencies.add::<T>();
+    }
+
+    fn identity(&self) -> Option<TypeIdentity> {
+        Some(T::identity())
+    }
+
+    fn is_unit_struct(&self) -> bool {
+        match T::camo().item {
+            Item::Struct(s) => matches!(s.content, StructContent::Unit),
+            Item::Enum(_) => false,
+        }
+    }
+}
+
+pub trait DescriptorProbe {
+    fn describe(&self, path: TypePath) -> Type;
+}
+
+impl<T: Descriptor + ?Sized> DescriptorProbe for Probe<T> {
+    fn describe(&self, path: TypePath) -> Type {
+        let arguments = match path.segments.last() {
+            Some(segment) => segment
+                .arguments
+                .iter()
+                .filter_map(|argument| match argument {
+                    GenericArgument::Type(ty) => Some(ty.clone()),
+                    GenericArgument::Lifetime(_) => None,
+                })
+                .collect(),
+            None => Vec::new(),
+        };
+        T::describe(arguments).unwrap_or(Type::Path(path))
+    }
+}
+
+pub trait FallbackProbe {
+    fn add_to(&self, dependencies: &mut Dependencies);
+
+    fn identity(&self) -> Option<TypeIdentity>;
+
+    fn is_unit_struct(&self) -> bool;
+
+    fn describe(&self, path: TypePath) -> Type;
+}
+
+impl<T: ?Sized> FallbackProbe for &Probe<T> {
+    fn add_to(&self, _: &mut Dependencies) {}
+
+    fn identity(&self) -> Option<TypeIdentity> {
+        None
+    }
+
+    fn is_unit_struct(&self) -> bool {
+        false
+    }
+
+    fn describe(&self, path: TypePath) -> Type {
+        Type::Path(path)
+    }
+}
@@ -1,27 +1,179 @@
 use std::convert::TryFrom;
+use std::fmt;
 
 /// A container of some type definition.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Container {
+    /// The path of the module in which the type is defined,
+    /// as given by `module_path!()` (e.g. `"my_crate::models"`).
+    pub module_path: &'static str,
     /// The attributes that were present on the type, if any.
     pub attributes: ContainerAttributes,
+    /// The documentation of the type.
+    pub docs: Docs,
     /// The item (type definition).
     pub item: Item,
 }
 
+impl Container {
+    /// The fully qualified identity of the type.
+    pub fn identity(&self) -> TypeIdentity {
+        TypeIdentity {
+            module_path: self.module_path,
+            name: self.item.name(),
+        }
+    }
+}
+
+/// The documentation of a type, field or variant.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Docs {
+    /// The lines of the documentation comment (i.e. the `#[doc]` attributes),
+    /// without their common leading whitespace.
+    pub lines: Vec<&'static str>,
+    /// A `#[deprecated]` attribute, if present.
+    pub deprecated: Option<Deprecation>,
+}
+
+impl Docs {
+    /// Returns `true` if there is neither a comment nor a deprecation.
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty() && self.deprecated.is_none()
+    }
+}
+
+/// A `#[deprecated]` attribute.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Deprecation {
+    /// The version since which the type, field or variant is deprecated, if given.
+    pub since: Option<&'static str>,
+    /// The reason for the deprecation, if given.
+    pub note: Option<&'static str>,
+}
+
+/// The fully qualified identity of a type definition, which distinguishes
+/// types with the same name in different modules.
+///
+/// Every instantiation of a generic type shares the same identity.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct TypeIdentity {
+    /// The path of the module in which the type is defined,
+    /// as given by `module_path!()`.
+    pub module_path: &'static str,
+    /// The name of the type, as written in Rust.
+    pub name: &'static str,
+}
+
+impl TypeIdentity {
+    /// The name of the crate in which the type is defined.
+    pub fn crate_name(&self) -> &'static str {
+        match self.module_path.find("::") {
+            Some(index) => &self.module_path[..index],
+            None => self.module_path,
+        }
+    }
+}
+
+impl fmt::Display for TypeIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}::{}", self.module_path, self.name)
+    }
+}
+
 /// The keys and associated values present in the attribute on a top-level type.
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct ContainerAttributes {
-    /// A `rename` attribute, signifying that the type itself should be renamed.
-    pub rename: Option<RenameRule>,
+    /// A `rename` attribute, signifying that the type itself should be renamed
+    /// to the given name.
+    pub rename: Option<&'static str>,
+    /// A `rename(serialize = "...")` attribute, which takes precedence
+    /// over `rename` when serializing.
+    pub rename_serialize: Option<&'static str>,
+    /// A `rename(deserialize = "...")` attribute, which takes precedence
+    /// over `rename` when deserializing.
+    pub rename_deserialize: Option<&'static str>,
     /// A `rename` attribute, signifying that the fields should be renamed.
     pub rename_all: Option<RenameRule>,
+    /// A `rename_all(serialize = "...")` attribute, which takes precedence
+    /// over `rename_all` when serializing.
+    pub rename_all_serialize: Option<RenameRule>,
+    /// A `rename_all(deserialize = "...")` attribute, which takes precedence
+    /// over `rename_all` when deserializing.
+    pub rename_all_deserialize: Option<RenameRule>,
     /// A `tag` attribute, signifying that the name of enum variants
     /// should be reflected in a separate field of the given name.
     pub tag: Option<&'static str>,
     /// A `content` attribute, signifying that the content of enum variants
     /// should be reflected in a separate field of the given name.
     pub content: Option<&'static str>,
+    /// An `untagged` attribute, signifying that enum variants are
+    /// serialized as their content alone, without any tag.
+    pub untagged: bool,
+    /// A `transparent` attribute, signifying that a struct with a single field
+    /// is serialized and deserialized as that field.
+    pub transparent: bool,
+    /// A `from` attribute, holding the type which is deserialized
+    /// and then converted into this type.
+    pub from: Option<Type>,
+    /// A `try_from` attribute, holding the type which is deserialized
+    /// and then fallibly converted into this type.
+    pub try_from: Option<Type>,
+    /// An `into` attribute, holding the type which this type is
+    /// converted into before being serialized.
+    pub into: Option<Type>,
+    /// A `default` attribute, signifying that any field of the struct
+    /// may be missing when deserializing.
+    pub default: bool,
+    /// A `#[camo(repr)]` marker, signifying that the enum is serialized
+    /// as the integer value of its discriminant (like with `serde_repr`).
+    pub repr: bool,
+    /// A `#[camo(rename = "...")]` attribute, which names the type
+    /// in the generated definitions only.
+    ///
+    /// This takes precedence over the `serde` renames.
+    pub camo_rename: Option<&'static str>,
+    /// A `#[camo(export = ...)]` attribute, deciding whether the generated
+    /// definition is exported regardless of the visibility of the type.
+    pub export: Option<bool>,
+}
+
+impl ContainerAttributes {
+    /// The name of the type in the given direction, if it is renamed.
+    pub fn rename_for(&self, direction: Direction) -> Option<&'static str> {
+        self.camo_rename.or(match direction {
+            Direction::Serialize => self.rename_serialize.or(self.rename),
+            Direction::Deserialize => self.rename_deserialize.or(self.rename),
+        })
+    }
+
+    /// The rule for renaming fields or variants in the given direction, if any.
+    pub fn rename_all_for(&self, direction: Direction) -> Option<RenameRule> {
+        match direction {
+            Direction::Serialize => self.rename_all_serialize.or(self.rename_all),
+            Direction::Deserialize => self.rename_all_deserialize.or(self.rename_all),
+        }
+    }
+
+    /// The type which stands in for this type in the given direction, if any
+    /// (`into` when serializing, `from` or `try_from` when deserializing).
+    pub fn proxy_for(&self, direction: Direction) -> Option<&Type> {
+        match direction {
+            Direction::Serialize => self.into.as_ref(),
+            Direction::Deserialize => self.from.as_ref().or(self.try_from.as_ref()),
+        }
+    }
+}
+
+/// The direction in which a value passes through `serde`.
+///
+/// A type can look different in each direction, e.g. when a field
+/// is marked with `skip_serializing` or `default`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Direction {
+    /// The value is serialized (i.e. it is produced by Rust).
+    Serialize,
+    /// The value is deserialized (i.e. it is consumed by Rust).
+    Deserialize,
 }
 
 /// A rename rule, signifying that something should be renamed
@@ -57,6 +209,16 @@ pub enum Item {
     Enum(Enum),
 }
 
+impl Item {
+    /// The name of the item.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Struct(ty) => ty.name,
+            Self::Enum(ty) => ty.name,
+        }
+    }
+}
+
 /// A visibility modifier for an item.
 #[derive(Debug, Clone, PartialEq)]
 pub enum Visibility {
@@ -94,10 +256,56 @@ pub struct Struct {
 /// A generic parameter.
 #[derive(Debug, Clone, PartialEq)]
 pub enum GenericParameter {
-    /// A plain type parameter.
-    Type(&'static str),
+    /// A type parameter.
+    Type(TypeParameter),
     /// A lifetime parameter.
     Lifetime(&'static str),
+    /// A const parameter.
+    Const(ConstParameter),
+}
+
+impl GenericParameter {
+    /// The name of the parameter.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Type(parameter) => parameter.name,
+            Self::Lifetime(name) => name,
+            Self::Const(parameter) => parameter.name,
+        }
+    }
+}
+
+/// A type parameter, e.g. `T: Clone = String`.
+///
+/// Bounds from a `where` clause on the parameter itself
+/// (e.g. `where T: Clone`) are included in its bounds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeParameter {
+    /// The name of the parameter.
+    pub name: &'static str,
+    /// The trait bounds of the parameter, e.g. `Clone`.
+    pub bounds: Vec<TypePath>,
+    /// The default type of the parameter, if any.
+    pub default: Option<Type>,
+}
+
+impl From<&'static str> for TypeParameter {
+    fn from(name: &'static str) -> Self {
+        Self {
+            name,
+            bounds: Vec::new(),
+            default: None,
+        }
+    }
+}
+
+/// A const parameter, e.g. `const N: usize`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstParameter {
+    /// The name of the parameter.
+    pub name: &'static str,
+    /// The type of the parameter.
+    pub ty: Type,
 }
 
 /// A list of fields.
@@ -114,31 +322,139 @@ pub enum StructContent {
     /// }
     /// ```
     NamedFields(Vec<NamedField>),
-    /// A struct that is composed of a single unnamed field.
+    /// A struct that is composed of any number of unnamed fields.
     ///
     /// Example:
     /// ```rs
     /// struct Example(i32);
+    /// struct Point(i32, i32);
     /// ```
-    UnnamedField(UnnamedField),
+    UnnamedFields(Vec<UnnamedField>),
+    /// A struct with no fields.
+    ///
+    /// Example:
+    /// ```rs
+    /// struct Example;
+    /// ```
+    Unit,
 }
 
 /// Represents a named `struct` field.
 #[derive(Debug, Clone, PartialEq)]
 pub struct NamedField {
+    /// The attributes placed directly on the field.
+    pub attributes: FieldAttributes,
+    /// The documentation of the field.
+    pub docs: Docs,
     /// The name of the field.
     pub name: &'static str,
     /// The type of the field.
     pub ty: Type,
 }
 
-/// Represents a named `struct` field.
+/// The attributes that are present on an individual field.
+///
+/// Unnamed fields cannot be renamed, and only the `skip` attributes
+/// of `serde` are read from them.
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct FieldAttributes {
+    /// A `rename` attribute, signifying that the field should be renamed
+    /// to the given name.
+    ///
+    /// This takes precedence over `rename_all` on the enclosing type or variant.
+    pub rename: Option<&'static str>,
+    /// A `rename(serialize = "...")` attribute, which takes precedence
+    /// over `rename` when serializing.
+    pub rename_serialize: Option<&'static str>,
+    /// A `rename(deserialize = "...")` attribute, which takes precedence
+    /// over `rename` when deserializing.
+    pub rename_deserialize: Option<&'static str>,
+    /// Any `alias` attributes, holding additional names that are
+    /// accepted when deserializing.
+    pub aliases: Vec<&'static str>,
+    /// A `skip` attribute, signifying that the field is neither
+    /// serialized nor deserialized.
+    pub skip: bool,
+    /// A `skip_serializing` attribute, signifying that the field
+    /// is never serialized.
+    pub skip_serializing: bool,
+    /// A `skip_deserializing` attribute, signifying that the field
+    /// is never deserialized.
+    pub skip_deserializing: bool,
+    /// A `default` attribute (with or without a function path), signifying
+    /// that the field may be missing when deserializing.
+    pub default: bool,
+    /// A `skip_serializing_if` attribute, holding the path of the predicate
+    /// which decides if the field is left out when serializing.
+    pub skip_serializing_if: Option<&'static str>,
+    /// A `flatten` attribute, signifying that the fields of the field's type
+    /// are inlined into the enclosing type.
+    pub flatten: bool,
+    /// A `#[camo(rename = "...")]` attribute, which names the field
+    /// in the generated definitions only.
+    ///
+    /// This takes precedence over the `serde` renames.
+    pub camo_rename: Option<&'static str>,
+    /// A `#[camo(skip)]` attribute, signifying that the field is left out
+    /// of the generated definitions, although `serde` may still include it.
+    pub camo_skip: bool,
+    /// A `#[camo(foreign = "...")]` attribute, signifying that the type
+    /// of the field has been replaced with the given type, which is
+    /// defined outside of the generated definitions.
+    pub foreign: bool,
+    /// A `#[camo(typescript = "...")]` attribute, holding the TypeScript type
+    /// that describes the field.
+    ///
+    /// Unless `#[camo(as = "...")]` is also given, the Rust type of the field
+    /// is then not described, and the type of the field is [`Type::Opaque`].
+    pub typescript: Option<&'static str>,
+}
+
+impl FieldAttributes {
+    /// The name of the field in the given direction, if it is renamed.
+    pub fn rename_for(&self, direction: Direction) -> Option<&'static str> {
+        self.camo_rename.or(match direction {
+            Direction::Serialize => self.rename_serialize.or(self.rename),
+            Direction::Deserialize => self.rename_deserialize.or(self.rename),
+        })
+    }
+
+    /// Returns `true` if the field is left out in the given direction.
+    pub fn is_skipped(&self, direction: Direction) -> bool {
+        self.camo_skip
+            || match direction {
+                Direction::Serialize => self.skip || self.skip_serializing,
+                Direction::Deserialize => self.skip || self.skip_deserializing,
+            }
+    }
+
+    /// Returns `true` if the field may be missing in the given direction.
+    pub fn is_optional(&self, direction: Direction) -> bool {
+        match direction {
+            Direction::Serialize => self.skip_serializing_if.is_some(),
+            Direction::Deserialize => self.default,
+        }
+    }
+}
+
+/// Represents an unnamed `struct` or enum variant field.
 #[derive(Debug, Clone, PartialEq)]
 pub struct UnnamedField {
+    /// The attributes placed directly on the field.
+    pub attributes: FieldAttributes,
     /// The type of the field.
     pub ty: Type,
 }
 
+impl From<Type> for UnnamedField {
+    fn from(value: Type) -> Self {
+        Self {
+            attributes: FieldAttributes::default(),
+            ty: value,
+        }
+    }
+}
+
 /// Represents an `enum` definition.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Enum {
@@ -152,13 +468,35 @@ pub struct Enum {
     pub variants: Vec<Variant>,
 }
 
+impl Enum {
+    /// Returns the discriminant value of each variant.
+    ///
+    /// Variants without an explicit discriminant are assigned
+    /// the value of the previous variant plus one, starting at zero.
+    pub fn discriminants(&self) -> Vec<i128> {
+        let mut next = 0;
+        self.variants
+            .iter()
+            .map(|variant| {
+                let value = variant.discriminant.unwrap_or(next);
+                next = value + 1;
+                value
+            })
+            .collect()
+    }
+}
+
 /// A variant of an enum.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Variant {
     /// The attributes placed directly on the variant.
     pub attributes: VariantAttributes,
+    /// The documentation of the variant.
+    pub docs: Docs,
     /// The name of the variant.
     pub name: &'static str,
+    /// The explicit discriminant of the variant, if any (e.g. `1` in `Active = 1`).
+    pub discriminant: Option<i128>,
     /// The content of the variant.
     pub content: VariantContent,
 }
@@ -166,12 +504,75 @@ pub struct Variant {
 /// The attributes that are present on an individual enum variant.
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct VariantAttributes {
-    /// A `rename` rule, signifying that the variant should be renamed.
+    /// A `rename` attribute, signifying that the variant should be renamed
+    /// to the given name.
     ///
     /// This takes precedence over `rename_all` on the top-level type.
-    pub rename: Option<RenameRule>,
+    pub rename: Option<&'static str>,
+    /// A `rename(serialize = "...")` attribute, which takes precedence
+    /// over `rename` when serializing.
+    pub rename_serialize: Option<&'static str>,
+    /// A `rename(deserialize = "...")` attribute, which takes precedence
+    /// over `rename` when deserializing.
+    pub rename_deserialize: Option<&'static str>,
+    /// Any `alias` attributes, holding additional names that are
+    /// accepted when deserializing.
+    pub aliases: Vec<&'static str>,
     /// A `rename_all` rule, signifying that all the fields in the variant should be renamed.
     pub rename_all: Option<RenameRule>,
+    /// A `rename_all(serialize = "...")` attribute, which takes precedence
+    /// over `rename_all` when serializing.
+    pub rename_all_serialize: Option<RenameRule>,
+    /// A `rename_all(deserialize = "...")` attribute, which takes precedence
+    /// over `rename_all` when deserializing.
+    pub rename_all_deserialize: Option<RenameRule>,
+    /// A `skip` attribute, signifying that the variant is neither
+    /// serialized nor deserialized.
+    pub skip: bool,
+    /// A `skip_serializing` attribute, signifying that the variant
+    /// is never serialized.
+    pub skip_serializing: bool,
+    /// A `skip_deserializing` attribute, signifying that the variant
+    /// is never deserialized.
+    pub skip_deserializing: bool,
+    /// An `untagged` attribute, signifying that the variant is serialized
+    /// as its content alone, regardless of how the enum is tagged.
+    pub untagged: bool,
+    /// A `#[camo(rename = "...")]` attribute, which names the variant
+    /// in the generated definitions only.
+    ///
+    /// This takes precedence over the `serde` renames.
+    pub camo_rename: Option<&'static str>,
+    /// A `#[camo(skip)]` attribute, signifying that the variant is left out
+    /// of the generated definitions, although `serde` may still include it.
+    pub camo_skip: bool,
+}
+
+impl VariantAttributes {
+    /// The name of the variant in the given direction, if it is renamed.
+    pub fn rename_for(&self, direction: Direction) -> Option<&'static str> {
+        self.camo_rename.or(match direction {
+            Direction::Serialize => self.rename_serialize.or(self.rename),
+            Direction::Deserialize => self.rename_deserialize.or(self.rename),
+        })
+    }
+
+    /// The rule for renaming fields in the given direction, if any.
+    pub fn rename_all_for(&self, direction: Direction) -> Option<RenameRule> {
+        match direction {
+            Direction::Serialize => self.rename_all_serialize.or(self.rename_all),
+            Direction::Deserialize => self.rename_all_deserialize.or(self.rename_all),
+        }
+    }
+
+    /// Returns `true` if the variant is left out in the given direction.
+    pub fn is_skipped(&self, direction: Direction) -> bool {
+        self.camo_skip
+            || match direction {
+                Direction::Serialize => self.skip || self.skip_serializing,
+                Direction::Deserialize => self.skip || self.skip_deserializing,
+            }
+    }
 }
 
 /// The content of an enum variant.
@@ -179,8 +580,8 @@ pub struct VariantAttributes {
 pub enum VariantContent {
     /// A unit variant.
     Unit,
-    /// A variant with a single unnamed field.
-    Unnamed(Type),
+    /// A variant with any number of unnamed fields.
+    Unnamed(Vec<UnnamedField>),
     /// A variant with any number of named fields.
     Named(Vec<NamedField>),
 }
@@ -197,6 +598,27 @@ pub enum Type {
     Slice(SliceType),
     /// A fixed-size array.
     Array(ArrayType),
+    /// A tuple type (e.g. `(i32, String)`), including the unit type `()`.
+    Tuple(TupleType),
+    /// A built-in type with a well-known representation (e.g. `String`),
+    /// as described by a [`Descriptor`](crate::Descriptor).
+    Builtin(BuiltinType),
+    /// A sequence of values (e.g. `Vec<T>` or `HashSet<T>`),
+    /// as described by a [`Descriptor`](crate::Descriptor).
+    Sequence(SequenceType),
+    /// A map from keys to values (e.g. `HashMap<K, V>`),
+    /// as described by a [`Descriptor`](crate::Descriptor).
+    Map(MapType),
+    /// A value that may be absent (e.g. `Option<T>`),
+    /// as described by a [`Descriptor`](crate::Descriptor).
+    Optional(OptionalType),
+    /// A pointer that is serialized as the value it points to
+    /// (e.g. `Box<T>` or `Arc<T>`),
+    /// as described by a [`Descriptor`](crate::Descriptor).
+    Pointer(PointerType),
+    /// A type that is not described, as for a field whose type is given
+    /// directly to each backend (e.g. with `#[camo(typescript = "...")]`).
+    Opaque,
 }
 
 /// The name of a type (struct or enum) declared elsewhere.
@@ -262,11 +684,93 @@ impl From<Type> for SliceType {
     }
 }
 
-/// An array type.
+/// An array type, e.g. `[u8; 4]`.
 #[derive(Debug, Clone, PartialEq)]
-pub struct ArrayType(pub Box<Type>);
+pub struct ArrayType {
+    /// The type of the elements.
+    pub ty: Box<Type>,
+    /// The length of the array, if it is given as a literal.
+    pub length: Option<usize>,
+}
+
+impl ArrayType {
+    /// An array of the given length.
+    pub fn with_length(ty: Type, length: usize) -> Self {
+        Self {
+            ty: Box::new(ty),
+            length: Some(length),
+        }
+    }
+}
 
 impl From<Type> for ArrayType {
+    fn from(value: Type) -> Self {
+        Self {
+            ty: Box::new(value),
+            length: None,
+        }
+    }
+}
+
+/// A tuple type.
+///
+/// The unit type `()` is represented as a tuple with no elements.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TupleType(pub Vec<Type>);
+
+impl TupleType {
+    /// The unit type, `()`.
+    pub fn unit() -> Self {
+        Self(Vec::new())
+    }
+
+    /// Returns `true` if the tuple is the unit type, `()`.
+    pub fn is_unit(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<Vec<Type>> for TupleType {
+    fn from(value: Vec<Type>) -> Self {
+        Self(value)
+    }
+}
+
+/// A sequence of values, serialized as a sequence.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SequenceType(pub Box<Type>);
+
+impl From<Type> for SequenceType {
+    fn from(value: Type) -> Self {
+        Self(Box::new(value))
+    }
+}
+
+/// A map from keys to values, serialized as a map.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MapType {
+    /// The type of the keys.
+    pub key: Box<Type>,
+    /// The type of the values.
+    pub value: Box<Type>,
+}
+
+/// A value that may be absent, serialized as either
+/// the value itself or as nothing (e.g. `null`).
+#[derive(Debug, Clone, PartialEq)]
+pub struct OptionalType(pub Box<Type>);
+
+impl From<Type> for OptionalType {
+    fn from(value: Type) -> Self {
+        Self(Box::new(value))
+    }
+}
+
+/// A pointer, serialized as the value it points to.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PointerType(pub Box<Type>);
+
+impl From<Type> for PointerType {
     fn from(value: Type) -> Self {
         Self(Box::new(value))
     }
@@ -295,7 +799,7 @@ pub enum BuiltinType {
     I16,
     /// The `i32` type.
     I32,
-    /// The `i32` type.
+    /// The `i64` type.
     I64,
     /// The `i128` type.
     I128,
@@ -307,6 +811,8 @@ pub enum BuiltinType {
     F64,
     /// The `char` type.
     Char,
+    /// A string, like `String` or `str`.
+    String,
 }
 
 impl TryFrom<TypePath> for BuiltinType {
@@ -1,8 +1,31 @@
-use crate::ast::Container;
+use crate::ast::{Container, TypeIdentity, TypePath};
+use crate::registry::Dependencies;
 
 /// Describes how to construct a type
 /// definition for a given type.
 pub trait Camo {
     /// Returns a description of the type.
     fn camo() -> Container;
+
+    /// Reports the types that the type definition refers to,
+    /// so that they can be collected by a [`TypeRegistry`](crate::TypeRegistry).
+    ///
+    /// The default implementation reports no dependencies.
+    fn dependencies(_: &mut Dependencies) {}
+
+    /// Returns the fully qualified identity of the type.
+    ///
+    /// The default implementation takes the identity from [`Camo::camo`].
+    fn identity() -> TypeIdentity {
+        Self::camo().identity()
+    }
+
+    /// Resolves a type path that occurs in the type definition
+    /// to the identity of the type it refers to, if that type
+    /// implements `Camo`.
+    ///
+    /// The default implementation resolves nothing.
+    fn resolve(_: &TypePath) -> Option<TypeIdentity> {
+        None
+    }
 }
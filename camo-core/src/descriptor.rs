@@ -0,0 +1,240 @@
+use std::borrow::{Cow, ToOwned};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+use std::marker::PhantomData;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use crate::ast::{
+    BuiltinType, Container, ContainerAttributes, Docs, Enum, GenericParameter, Item, MapType,
+    NamedField, OptionalType, PathSegment, PointerType, SequenceType, Struct, StructContent,
+    TupleType, Type, TypeParameter, TypePath, UnnamedField, Variant, VariantAttributes,
+    VariantContent, Visibility,
+};
+use crate::camo::Camo;
+
+/// Describes how a type that is not a type definition of its own
+/// (like most types in the standard library) is serialized by `serde`.
+///
+/// The `Camo` derive macro uses the descriptor of a field's type when
+/// it is available, so that e.g. `Vec<T>` is described as a sequence,
+/// while a type named `Vec` that is declared elsewhere is left as a path.
+pub trait Descriptor {
+    /// Describes the type, given the type arguments it was written with.
+    ///
+    /// Returns `None` if the type cannot be described with the given
+    /// arguments (e.g. when it is named through a type alias).
+    fn describe(arguments: Vec<Type>) -> Option<Type>;
+}
+
+macro_rules! builtin {
+    ($($ty:ty => $builtin:ident,)*) => {
+        $(
+            impl Descriptor for $ty {
+                fn describe(_: Vec<Type>) -> Option<Type> {
+                    Some(Type::Builtin(BuiltinType::$builtin))
+                }
+            }
+        )*
+    };
+}
+
+builtin! {
+    String => String,
+    str => String,
+    PathBuf => String,
+    Path => String,
+    IpAddr => String,
+    Ipv4Addr => String,
+    Ipv6Addr => String,
+    SocketAddr => String,
+    NonZeroU8 => U8,
+    NonZeroU16 => U16,
+    NonZeroU32 => U32,
+    NonZeroU64 => U64,
+    NonZeroU128 => U128,
+    NonZeroUsize => Usize,
+    NonZeroI8 => I8,
+    NonZeroI16 => I16,
+    NonZeroI32 => I32,
+    NonZeroI64 => I64,
+    NonZeroI128 => I128,
+    NonZeroIsize => Isize,
+}
+
+/// The first type argument, which is the only one that matters for most types.
+fn first(arguments: Vec<Type>) -> Option<Type> {
+    arguments.into_iter().next()
+}
+
+impl<T> Descriptor for Vec<T> {
+    fn describe(arguments: Vec<Type>) -> Option<Type> {
+        first(arguments).map(|ty| Type::Sequence(SequenceType::from(ty)))
+    }
+}
+
+impl<T> Descriptor for VecDeque<T> {
+    fn describe(arguments: Vec<Type>) -> Option<Type> {
+        first(arguments).map(|ty| Type::Sequence(SequenceType::from(ty)))
+    }
+}
+
+impl<T, S> Descriptor for HashSet<T, S> {
+    fn describe(arguments: Vec<Type>) -> Option<Type> {
+        first(arguments).map(|ty| Type::Sequence(SequenceType::from(ty)))
+    }
+}
+
+impl<T> Descriptor for BTreeSet<T> {
+    fn describe(arguments: Vec<Type>) -> Option<Type> {
+        first(arguments).map(|ty| Type::Sequence(SequenceType::from(ty)))
+    }
+}
+
+/// The key and value type arguments of a map.
+fn map(arguments: Vec<Type>) -> Option<Type> {
+    let mut arguments = arguments.into_iter();
+    let key = arguments.next()?;
+    let value = arguments.next()?;
+    Some(Type::Map(MapType {
+        key: Box::new(key),
+        value: Box::new(value),
+    }))
+}
+
+impl<K, V, S> Descriptor for HashMap<K, V, S> {
+    fn describe(arguments: Vec<Type>) -> Option<Type> {
+        map(arguments)
+    }
+}
+
+impl<K, V> Descriptor for BTreeMap<K, V> {
+    fn describe(arguments: Vec<Type>) -> Option<Type> {
+        map(arguments)
+    }
+}
+
+impl<T> Descriptor for Option<T> {
+    fn describe(arguments: Vec<Type>) -> Option<Type> {
+        first(arguments).map(|ty| Type::Optional(OptionalType::from(ty)))
+    }
+}
+
+impl<T: ?Sized> Descriptor for Box<T> {
+    fn describe(arguments: Vec<Type>) -> Option<Type> {
+        first(arguments).map(|ty| Type::Pointer(PointerType::from(ty)))
+    }
+}
+
+impl<T: ?Sized> Descriptor for Rc<T> {
+    fn describe(arguments: Vec<Type>) -> Option<Type> {
+        first(arguments).map(|ty| Type::Pointer(PointerType::from(ty)))
+    }
+}
+
+impl<T: ?Sized> Descriptor for Arc<T> {
+    fn describe(arguments: Vec<Type>) -> Option<Type> {
+        first(arguments).map(|ty| Type::Pointer(PointerType::from(ty)))
+    }
+}
+
+impl<B: ?Sized + ToOwned> Descriptor for Cow<'_, B> {
+    fn describe(arguments: Vec<Type>) -> Option<Type> {
+        first(arguments).map(|ty| Type::Pointer(PointerType::from(ty)))
+    }
+}
+
+/// `PhantomData` is serialized as a unit struct (e.g. `null`).
+impl<T: ?Sized> Descriptor for PhantomData<T> {
+    fn describe(_: Vec<Type>) -> Option<Type> {
+        Some(Type::Tuple(TupleType::unit()))
+    }
+}
+
+fn path(name: &'static str) -> Type {
+    Type::Path(TypePath::from([PathSegment {
+        name,
+        arguments: Vec::new(),
+    }]))
+}
+
+fn named_field(name: &'static str, ty: Type) -> NamedField {
+    NamedField {
+        attributes: Default::default(),
+        docs: Docs::default(),
+        name,
+        ty,
+    }
+}
+
+/// `Result` is serialized like an externally tagged enum,
+/// i. e. `{ "Ok": T }` or `{ "Err": E }`.
+impl<T, E> Camo for Result<T, E> {
+    fn camo() -> Container {
+        let variant = |name, ty| Variant {
+            attributes: VariantAttributes::default(),
+            docs: Docs::default(),
+            name,
+            discriminant: None,
+            content: VariantContent::Unnamed(Vec::from([UnnamedField::from(path(ty))])),
+        };
+        Container {
+            module_path: "core::result",
+            attributes: ContainerAttributes::default(),
+            docs: Docs::default(),
+            item: Item::Enum(Enum {
+                visibility: Visibility::Pub,
+                name: "Result",
+                parameters: Vec::from([
+                    GenericParameter::Type(TypeParameter::from("T")),
+                    GenericParameter::Type(TypeParameter::from("E")),
+                ]),
+                variants: Vec::from([variant("Ok", "T"), variant("Err", "E")]),
+            }),
+        }
+    }
+}
+
+impl Camo for Duration {
+    fn camo() -> Container {
+        Container {
+            module_path: "core::time",
+            attributes: ContainerAttributes::default(),
+            docs: Docs::default(),
+            item: Item::Struct(Struct {
+                visibility: Visibility::Pub,
+                name: "Duration",
+                parameters: Vec::new(),
+                content: StructContent::NamedFields(Vec::from([
+                    named_field("secs", path("u64")),
+                    named_field("nanos", path("u32")),
+                ])),
+            }),
+        }
+    }
+}
+
+impl Camo for SystemTime {
+    fn camo() -> Container {
+        Container {
+            module_path: "std::time",
+            attributes: ContainerAttributes::default(),
+            docs: Docs::default(),
+            item: Item::Struct(Struct {
+                visibility: Visibility::Pub,
+                name: "SystemTime",
+                parameters: Vec::new(),
+                content: StructContent::NamedFields(Vec::from([
+                    named_field("secs_since_epoch", path("u64")),
+                    named_field("nanos_since_epoch", path("u32")),
+                ])),
+            }),
+        }
+    }
+}
@@ -0,0 +1,371 @@
+use std::borrow::Cow;
+use std::fmt;
+
+use crate::ast::{
+    ArrayType, Container, GenericArgument, GenericParameter, Item, MapType, NamedField,
+    OptionalType, PathSegment, PointerType, ReferenceType, SequenceType, SliceType, StructContent,
+    TupleType, Type, TypePath, UnnamedField, Variant, VariantContent,
+};
+
+/// A generic container with type arguments substituted for its
+/// type parameters, as created by [`Container::instantiate`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Instance {
+    /// The name of the instance (e.g. `PageUser` for `Page<User>`).
+    pub name: Cow<'static, str>,
+    /// The container with its type parameters substituted.
+    ///
+    /// The item keeps the name of the generic container, by which
+    /// the container is known in Rust.
+    pub container: Container,
+}
+
+/// The reason that a container could not be instantiated.
+#[derive(Debug, Clone, PartialEq)]
+pub enum InstantiationError {
+    /// More arguments were given than the container has type parameters.
+    TooManyArguments {
+        /// The name of the container.
+        container: &'static str,
+        /// The number of type parameters of the container.
+        parameters: usize,
+        /// The number of arguments that were given.
+        arguments: usize,
+    },
+    /// A type parameter without a default type was not given an argument.
+    MissingArgument {
+        /// The name of the container.
+        container: &'static str,
+        /// The name of the type parameter.
+        parameter: &'static str,
+    },
+}
+
+impl fmt::Display for InstantiationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TooManyArguments {
+                container,
+                parameters,
+                arguments,
+            } => write!(
+                f,
+                "`{}` has {} type parameters, but {} arguments were given",
+                container, parameters, arguments
+            ),
+            Self::MissingArgument {
+                container,
+                parameter,
+            } => write!(
+                f,
+                "`{}` requires an argument for the type parameter `{}`",
+                container, parameter
+            ),
+        }
+    }
+}
+
+impl std::error::Error for InstantiationError {}
+
+impl Container {
+    /// Substitutes the given types for the type parameters of the container,
+    /// in order, and names the resulting instance `name`.
+    ///
+    /// Type parameters without a corresponding argument are given their
+    /// default type. Lifetime and const parameters are kept as they are.
+    ///
+    /// See [`Container::instance_name`] for a name to give the instance.
+    ///
+    /// Example:
+    ///
+    /// ```
+    /// use camo_core::{
+    ///     BuiltinType, Container, GenericParameter, Item, NamedField, PathSegment, Struct,
+    ///     StructContent, Type, TypeParameter, TypePath, Visibility,
+    /// };
+    ///
+    /// let page = Container {
+    ///     module_path: "example",
+    ///     attributes: Default::default(),
+    ///     docs: Default::default(),
+    ///     item: Item::Struct(Struct {
+    ///         visibility: Visibility::Pub,
+    ///         name: "Page",
+    ///         parameters: Vec::from([GenericParameter::Type(TypeParameter::from("T"))]),
+    ///         content: StructContent::NamedFields(Vec::from([NamedField {
+    ///             attributes: Default::default(),
+    ///             docs: Default::default(),
+    ///             name: "items",
+    ///             ty: Type::Path(TypePath::from([PathSegment {
+    ///                 name: "T",
+    ///                 arguments: Vec::new(),
+    ///             }])),
+    ///         }])),
+    ///     }),
+    /// };
+    ///
+    /// let arguments = Vec::from([Type::Builtin(BuiltinType::String)]);
+    /// let name = page.instance_name(&arguments);
+    /// assert_eq!(name, "PageString");
+    ///
+    /// let instance = page.instantiate(name, arguments).unwrap();
+    /// assert_eq!(instance.name, "PageString");
+    /// ```
+    ///
+    /// Returns an error if more arguments are given than there are type parameters,
+    /// or if a type parameter without a default type is not given an argument.
+    pub fn instantiate<N>(
+        self,
+        name: N,
+        arguments: Vec<Type>,
+    ) -> Result<Instance, InstantiationError>
+    where
+        N: Into<Cow<'static, str>>,
+    {
+        let parameters = match &self.item {
+            Item::Struct(s) => &s.parameters,
+            Item::Enum(e) => &e.parameters,
+        };
+        let type_parameters: Vec<_> = parameters
+            .iter()
+            .filter_map(|parameter| match parameter {
+                GenericParameter::Type(parameter) => Some(parameter),
+                GenericParameter::Lifetime(_) | GenericParameter::Const(_) => None,
+            })
+            .collect();
+        if arguments.len() > type_parameters.len() {
+            return Err(InstantiationError::TooManyArguments {
+                container: self.item.name(),
+                parameters: type_parameters.len(),
+                arguments: arguments.len(),
+            });
+        }
+
+        // Defaults may refer to the parameters before them, e.g. `U = Vec<T>`
+        let mut substitution = Substitution(Vec::new());
+        let mut arguments = arguments.into_iter();
+        for parameter in type_parameters {
+            let argument = match (arguments.next(), &parameter.default) {
+                (Some(argument), _) => argument,
+                (None, Some(default)) => substitution.apply(default.clone()),
+                (None, None) => {
+                    return Err(InstantiationError::MissingArgument {
+                        container: self.item.name(),
+                        parameter: parameter.name,
+                    })
+                }
+            };
+            substitution.0.push((parameter.name, argument));
+        }
+
+        let Container {
+            module_path,
+            mut attributes,
+            docs,
+            item,
+        } = self;
+        attributes.from = attributes.from.map(|ty| substitution.apply(ty));
+        attributes.try_from = attributes.try_from.map(|ty| substitution.apply(ty));
+        attributes.into = attributes.into.map(|ty| substitution.apply(ty));
+        let without_types = |parameters: Vec<GenericParameter>| {
+            parameters
+                .into_iter()
+                .filter(|parameter| !matches!(parameter, GenericParameter::Type(_)))
+                .collect()
+        };
+        let item = match item {
+            Item::Struct(mut s) => {
+                s.parameters = without_types(s.parameters);
+                s.content = match s.content {
+                    StructContent::NamedFields(fields) => {
+                        StructContent::NamedFields(substitution.named_fields(fields))
+                    }
+                    StructContent::UnnamedFields(fields) => {
+                        StructContent::UnnamedFields(substitution.unnamed_fields(fields))
+                    }
+                    StructContent::Unit => StructContent::Unit,
+                };
+                Item::Struct(s)
+            }
+            Item::Enum(mut e) => {
+                e.parameters = without_types(e.parameters);
+                e.variants = e
+                    .variants
+                    .into_iter()
+                    .map(|variant| Variant {
+                        content: match variant.content {
+                            VariantContent::Named(fields) => {
+                                VariantContent::Named(substitution.named_fields(fields))
+                            }
+                            VariantContent::Unnamed(fields) => {
+                                VariantContent::Unnamed(substitution.unnamed_fields(fields))
+                            }
+                            VariantContent::Unit => VariantContent::Unit,
+                        },
+                        ..variant
+                    })
+                    .collect();
+                Item::Enum(e)
+            }
+        };
+        Ok(Instance {
+            name: name.into(),
+            container: Container {
+                module_path,
+                attributes,
+                docs,
+                item,
+            },
+        })
+    }
+
+    /// A name for the instance of the container with the given type arguments,
+    /// which is the name of the container followed by the names of the arguments,
+    /// e.g. `PageUser` for `Page<User>`.
+    ///
+    /// Arguments are named by the last segment of their path and their own
+    /// arguments (e.g. `ResultUserError` for `Result<User, Error>`),
+    /// while other types are named by their content, like
+    /// `UserList` for `Vec<User>`, `OptionalUser` for `Option<User>`
+    /// and `StringUserMap` for `HashMap<String, User>`.
+    pub fn instance_name(&self, arguments: &[Type]) -> String {
+        let mut name = String::from(self.item.name());
+        for argument in arguments {
+            push_name(&mut name, argument);
+        }
+        name
+    }
+}
+
+fn push_name(name: &mut String, ty: &Type) {
+    match ty {
+        Type::Path(path) => {
+            if let Some(segment) = path.segments.last() {
+                let mut chars = segment.name.chars();
+                if let Some(first) = chars.next() {
+                    name.extend(first.to_uppercase());
+                    name.extend(chars);
+                }
+                for argument in &segment.arguments {
+                    if let GenericArgument::Type(ty) = argument {
+                        push_name(name, ty);
+                    }
+                }
+            }
+        }
+        Type::Builtin(ty) => name.push_str(&format!("{:?}", ty)),
+        Type::Reference(ty) => push_name(name, &ty.ty),
+        Type::Pointer(ty) => push_name(name, &ty.0),
+        Type::Opaque => name.push_str("Opaque"),
+        Type::Slice(SliceType(ty)) | Type::Sequence(SequenceType(ty)) => {
+            push_name(name, ty);
+            name.push_str("List");
+        }
+        Type::Array(ty) => {
+            push_name(name, &ty.ty);
+            name.push_str("List");
+        }
+        Type::Optional(ty) => {
+            name.push_str("Optional");
+            push_name(name, &ty.0);
+        }
+        Type::Map(ty) => {
+            push_name(name, &ty.key);
+            push_name(name, &ty.value);
+            name.push_str("Map");
+        }
+        Type::Tuple(ty) => {
+            if ty.is_unit() {
+                name.push_str("Unit");
+            }
+            for ty in &ty.0 {
+                push_name(name, ty);
+            }
+        }
+    }
+}
+
+/// The types to substitute for type parameters, by the name of the parameter.
+struct Substitution(Vec<(&'static str, Type)>);
+
+impl Substitution {
+    fn named_fields(&self, fields: Vec<NamedField>) -> Vec<NamedField> {
+        fields
+            .into_iter()
+            .map(|field| NamedField {
+                ty: self.apply(field.ty),
+                ..field
+            })
+            .collect()
+    }
+
+    fn unnamed_fields(&self, fields: Vec<UnnamedField>) -> Vec<UnnamedField> {
+        fields
+            .into_iter()
+            .map(|field| UnnamedField {
+                ty: self.apply(field.ty),
+                ..field
+            })
+            .collect()
+    }
+
+    fn apply(&self, ty: Type) -> Type {
+        match ty {
+            Type::Path(path) => match self.parameter(&path) {
+                Some(ty) => ty.clone(),
+                None => Type::Path(TypePath {
+                    segments: path
+                        .segments
+                        .into_iter()
+                        .map(|segment| PathSegment {
+                            name: segment.name,
+                            arguments: segment
+                                .arguments
+                                .into_iter()
+                                .map(|argument| match argument {
+                                    GenericArgument::Type(ty) => {
+                                        GenericArgument::Type(self.apply(ty))
+                                    }
+                                    argument => argument,
+                                })
+                                .collect(),
+                        })
+                        .collect(),
+                }),
+            },
+            Type::Reference(ty) => Type::Reference(ReferenceType {
+                lifetime: ty.lifetime,
+                ty: Box::new(self.apply(*ty.ty)),
+            }),
+            Type::Slice(ty) => Type::Slice(SliceType::from(self.apply(*ty.0))),
+            Type::Array(ty) => Type::Array(ArrayType {
+                ty: Box::new(self.apply(*ty.ty)),
+                length: ty.length,
+            }),
+            Type::Tuple(ty) => Type::Tuple(TupleType(
+                ty.0.into_iter().map(|ty| self.apply(ty)).collect(),
+            )),
+            Type::Builtin(ty) => Type::Builtin(ty),
+            Type::Sequence(ty) => Type::Sequence(SequenceType::from(self.apply(*ty.0))),
+            Type::Map(ty) => Type::Map(MapType {
+                key: Box::new(self.apply(*ty.key)),
+                value: Box::new(self.apply(*ty.value)),
+            }),
+            Type::Optional(ty) => Type::Optional(OptionalType::from(self.apply(*ty.0))),
+            Type::Pointer(ty) => Type::Pointer(PointerType::from(self.apply(*ty.0))),
+            Type::Opaque => Type::Opaque,
+        }
+    }
+
+    /// The type to substitute for the path, if it names a type parameter.
+    fn parameter(&self, path: &TypePath) -> Option<&Type> {
+        match path.segments.as_slice() {
+            [segment] if segment.arguments.is_empty() => self
+                .0
+                .iter()
+                .find(|(name, _)| *name == segment.name)
+                .map(|(_, ty)| ty),
+            _ => None,
+        }
+    }
+}
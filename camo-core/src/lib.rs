@@ -5,8 +5,17 @@
 
 mod ast;
 mod camo;
+mod descriptor;
+mod instance;
+mod registry;
 #[cfg(test)]
 mod tests;
 
 pub use crate::ast::*;
 pub use crate::camo::*;
+pub use crate::descriptor::*;
+pub use crate::instance::*;
+pub use crate::registry::*;
+
+#[doc(hidden)]
+pub mod __private;
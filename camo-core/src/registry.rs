@@ -0,0 +1,280 @@
+use std::collections::HashMap;
+
+use crate::ast::{Container, TypeIdentity, TypePath};
+use crate::camo::Camo;
+
+/// The types referenced by a type definition, as reported
+/// by [`Camo::dependencies`].
+#[derive(Default)]
+pub struct Dependencies {
+    types: Vec<TypeNode>,
+}
+
+impl Dependencies {
+    /// Records a dependency on the type `T`.
+    pub fn add<T: Camo>(&mut self) {
+        self.types.push(TypeNode::of::<T>());
+    }
+}
+
+/// A type that implements [`Camo`], along with the means
+/// to describe it and its dependencies.
+#[derive(Clone, Copy)]
+struct TypeNode {
+    identity: TypeIdentity,
+    camo: fn() -> Container,
+    dependencies: fn(&mut Dependencies),
+    resolve: fn(&TypePath) -> Option<TypeIdentity>,
+}
+
+impl TypeNode {
+    fn of<T: Camo>() -> Self {
+        Self {
+            identity: T::identity(),
+            camo: T::camo,
+            dependencies: T::dependencies,
+            resolve: T::resolve,
+        }
+    }
+}
+
+/// A collection of type definitions, along with every
+/// type definition they (transitively) depend on.
+///
+/// Example:
+///
+/// ```
+/// use camo_core::{Camo, Container, Dependencies, TypeRegistry};
+/// # use camo_core::{Item, Struct, StructContent, Visibility};
+///
+/// struct User;
+/// # impl Camo for User {
+/// #     fn camo() -> Container {
+/// #         Container {
+/// #             module_path: module_path!(),
+/// #             attributes: Default::default(),
+/// #             docs: Default::default(),
+/// #             item: Item::Struct(Struct {
+/// #                 visibility: Visibility::Pub,
+/// #                 name: "User",
+/// #                 parameters: Vec::new(),
+/// #                 content: StructContent::Unit,
+/// #             }),
+/// #         }
+/// #     }
+/// # }
+///
+/// struct Session;
+/// impl Camo for Session {
+///     fn camo() -> Container {
+///         /* ... */
+/// #         Container {
+/// #             module_path: module_path!(),
+/// #             attributes: Default::default(),
+/// #             docs: Default::default(),
+/// #             item: Item::Struct(Struct {
+/// #                 visibility: Visibility::Pub,
+/// #                 name: "Session",
+/// #                 parameters: Vec::new(),
+/// #                 content: StructContent::Unit,
+/// #             }),
+/// #         }
+///     }
+///
+///     fn dependencies(dependencies: &mut Dependencies) {
+///         dependencies.add::<User>();
+///     }
+/// }
+///
+/// let mut registry = TypeRegistry::new();
+/// registry.add::<Session>();
+///
+/// let names: Vec<_> = registry
+///     .containers()
+///     .into_iter()
+///     .map(|container| container.item.name())
+///     .collect();
+/// assert_eq!(names, ["User", "Session"]);
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct TypeRegistry {
+    entries: Vec<Entry>,
+    indices: HashMap<TypeIdentity, usize>,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    container: Container,
+    dependencies: Vec<usize>,
+    resolve: fn(&TypePath) -> Option<TypeIdentity>,
+}
+
+impl TypeRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the type `T` to the registry, along with
+    /// every type it (transitively) depends on.
+    ///
+    /// Types that are already present are not added again.
+    pub fn add<T: Camo>(&mut self) {
+        self.insert(TypeNode::of::<T>());
+    }
+
+    fn insert(&mut self, node: TypeNode) -> usize {
+        if let Some(&index) = self.indices.get(&node.identity) {
+            return index;
+        }
+
+        let index = self.entries.len();
+        self.indices.insert(node.identity, index);
+        self.entries.push(Entry {
+            container: (node.camo)(),
+            dependencies: Vec::new(),
+            resolve: node.resolve,
+        });
+
+        let mut dependencies = Dependencies::default();
+        (node.dependencies)(&mut dependencies);
+        let mut indices: Vec<usize> = dependencies
+            .types
+            .into_iter()
+            .map(|node| self.insert(node))
+            .collect();
+        indices.sort_unstable();
+        indices.dedup();
+        self.entries[index].dependencies = indices;
+
+        index
+    }
+
+    /// The number of type definitions in the registry.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the registry contains no type definitions.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the type definition with the given identity, if it is present.
+    pub fn get(&self, identity: &TypeIdentity) -> Option<&Container> {
+        self.indices
+            .get(identity)
+            .map(|&index| &self.entries[index].container)
+    }
+
+    /// Resolves a type path that occurs in the given type definition
+    /// to the type definition it refers to, if it is present.
+    ///
+    /// The path is resolved by the `Camo` implementation of the
+    /// type definition, so paths like `User`, `crate::models::User`
+    /// and `super::User` all resolve to the same type definition.
+    pub fn resolve(&self, container: &Container, path: &TypePath) -> Option<&Container> {
+        let index = *self.indices.get(&container.identity())?;
+        let identity = (self.entries[index].resolve)(path)?;
+        self.get(&identity)
+    }
+
+    /// Returns every type definition in the registry, ordered so that
+    /// each definition comes after the definitions it depends on.
+    ///
+    /// Definitions that depend on each other (directly or indirectly)
+    /// are kept together, in the order they were added.
+    pub fn containers(&self) -> Vec<&Container> {
+        self.components()
+            .into_iter()
+            .flatten()
+            .map(|index| &self.entries[index].container)
+            .collect()
+    }
+
+    /// Returns each group of type definitions that depend on each other,
+    /// such as a recursive type that refers to itself through a `Box`.
+    pub fn cycles(&self) -> Vec<Vec<&Container>> {
+        self.components()
+            .into_iter()
+            .filter(|component| match component.as_slice() {
+                [index] => self.entries[*index].dependencies.contains(index),
+                _ => true,
+            })
+            .map(|component| {
+                component
+                    .into_iter()
+                    .map(|index| &self.entries[index].container)
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The strongly connected components of the dependency graph,
+    /// in dependency order.
+    fn components(&self) -> Vec<Vec<usize>> {
+        let mut tarjan = Tarjan {
+            entries: &self.entries,
+            next: 0,
+            indices: vec![None; self.entries.len()],
+            lowlinks: vec![0; self.entries.len()],
+            stack: Vec::new(),
+            on_stack: vec![false; self.entries.len()],
+            components: Vec::new(),
+        };
+        for index in 0..self.entries.len() {
+            if tarjan.indices[index].is_none() {
+                tarjan.visit(index);
+            }
+        }
+        tarjan.components
+    }
+}
+
+/// Tarjan's strongly connected components algorithm, which emits
+/// each component after every component it depends on.
+struct Tarjan<'a> {
+    entries: &'a [Entry],
+    next: usize,
+    indices: Vec<Option<usize>>,
+    lowlinks: Vec<usize>,
+    stack: Vec<usize>,
+    on_stack: Vec<bool>,
+    components: Vec<Vec<usize>>,
+}
+
+impl Tarjan<'_> {
+    fn visit(&mut self, node: usize) {
+        self.indices[node] = Some(self.next);
+        self.lowlinks[node] = self.next;
+        self.next += 1;
+        self.stack.push(node);
+        self.on_stack[node] = true;
+
+        for &dependency in &self.entries[node].dependencies {
+            match self.indices[dependency] {
+                None => {
+                    self.visit(dependency);
+                    self.lowlinks[node] = self.lowlinks[node].min(self.lowlinks[dependency]);
+                }
+                Some(index) if self.on_stack[dependency] => {
+                    self.lowlinks[node] = self.lowlinks[node].min(index);
+                }
+                Some(_) => {}
+            }
+        }
+
+        if Some(self.lowlinks[node]) == self.indices[node] {
+            let mut component = Vec::new();
+            while let Some(member) = self.stack.pop() {
+                self.on_stack[member] = false;
+                component.push(member);
+                if member == node {
+                    break;
+                }
+            }
+            component.sort_unstable();
+            self.components.push(component);
+        }
+    }
+}
@@ -1,6 +1,12 @@
+use std::borrow::Cow;
 use std::convert::TryFrom;
 
-use crate::{BuiltinType, PathSegment, TypePath};
+use crate::{
+    ArrayType, BuiltinType, Camo, Container, Dependencies, Direction, Docs, Enum, FieldAttributes,
+    GenericArgument, GenericParameter, Instance, InstantiationError, Item, Lifetime, NamedField,
+    PathSegment, ReferenceType, SequenceType, Struct, StructContent, Type, TypeParameter, TypePath,
+    TypeRegistry, UnnamedField, Variant, VariantAttributes, VariantContent, Visibility,
+};
 
 #[test]
 fn type_path_from_segments() {
@@ -29,3 +35,369 @@ fn builtin_from_type_path() {
 
     assert_eq!(BuiltinType::try_from(path), Ok(BuiltinType::I32));
 }
+
+#[test]
+fn enum_discriminants() {
+    let variant = |name, discriminant| Variant {
+        attributes: VariantAttributes::default(),
+        docs: Docs::default(),
+        name,
+        discriminant,
+        content: VariantContent::Unit,
+    };
+
+    let ty = Enum {
+        visibility: Visibility::None,
+        name: "Status",
+        parameters: Vec::new(),
+        variants: Vec::from([
+            variant("Zero", None),
+            variant("Five", Some(5)),
+            variant("Six", None),
+            variant("MinusOne", Some(-1)),
+            variant("Zero", None),
+        ]),
+    };
+
+    assert_eq!(ty.discriminants(), Vec::from([0, 5, 6, -1, 0]));
+}
+
+#[test]
+fn field_attributes_direction() {
+    let attributes = FieldAttributes {
+        rename: Some("name"),
+        rename_deserialize: Some("input_name"),
+        skip_serializing: true,
+        default: true,
+        ..FieldAttributes::default()
+    };
+
+    assert_eq!(attributes.rename_for(Direction::Serialize), Some("name"));
+    assert_eq!(
+        attributes.rename_for(Direction::Deserialize),
+        Some("input_name")
+    );
+    assert!(attributes.is_skipped(Direction::Serialize));
+    assert!(!attributes.is_skipped(Direction::Deserialize));
+    assert!(attributes.is_optional(Direction::Deserialize));
+}
+
+#[test]
+fn camo_attributes_take_precedence() {
+    let attributes = FieldAttributes {
+        rename: Some("name"),
+        rename_serialize: Some("output_name"),
+        camo_rename: Some("displayName"),
+        camo_skip: true,
+        ..FieldAttributes::default()
+    };
+
+    assert_eq!(
+        attributes.rename_for(Direction::Serialize),
+        Some("displayName")
+    );
+    assert_eq!(
+        attributes.rename_for(Direction::Deserialize),
+        Some("displayName")
+    );
+    assert!(attributes.is_skipped(Direction::Serialize));
+    assert!(attributes.is_skipped(Direction::Deserialize));
+}
+
+fn unit_struct(name: &'static str) -> Container {
+    Container {
+        module_path: module_path!(),
+        attributes: Default::default(),
+        docs: Docs::default(),
+        item: Item::Struct(Struct {
+            visibility: Visibility::Pub,
+            name,
+            parameters: Vec::new(),
+            content: StructContent::Unit,
+        }),
+    }
+}
+
+fn names(containers: Vec<&Container>) -> Vec<&'static str> {
+    containers
+        .into_iter()
+        .map(|container| container.item.name())
+        .collect()
+}
+
+struct Leaf;
+
+impl Camo for Leaf {
+    fn camo() -> Container {
+        unit_struct("Leaf")
+    }
+}
+
+struct Branch;
+
+impl Camo for Branch {
+    fn camo() -> Container {
+        unit_struct("Branch")
+    }
+
+    fn dependencies(dependencies: &mut Dependencies) {
+        dependencies.add::<Leaf>();
+        dependencies.add::<Leaf>();
+    }
+}
+
+struct Root;
+
+impl Camo for Root {
+    fn camo() -> Container {
+        unit_struct("Root")
+    }
+
+    fn dependencies(dependencies: &mut Dependencies) {
+        dependencies.add::<Branch>();
+        dependencies.add::<Leaf>();
+    }
+}
+
+#[test]
+fn registry_dependency_order() {
+    let mut registry = TypeRegistry::new();
+    registry.add::<Root>();
+    registry.add::<Branch>();
+
+    assert_eq!(registry.len(), 3);
+    assert_eq!(names(registry.containers()), ["Leaf", "Branch", "Root"]);
+    assert!(registry.cycles().is_empty());
+}
+
+struct Recursive;
+
+impl Camo for Recursive {
+    fn camo() -> Container {
+        unit_struct("Recursive")
+    }
+
+    fn dependencies(dependencies: &mut Dependencies) {
+        dependencies.add::<Recursive>();
+        dependencies.add::<Leaf>();
+    }
+}
+
+struct Even;
+
+impl Camo for Even {
+    fn camo() -> Container {
+        unit_struct("Even")
+    }
+
+    fn dependencies(dependencies: &mut Dependencies) {
+        dependencies.add::<Odd>();
+    }
+}
+
+struct Odd;
+
+impl Camo for Odd {
+    fn camo() -> Container {
+        unit_struct("Odd")
+    }
+
+    fn dependencies(dependencies: &mut Dependencies) {
+        dependencies.add::<Even>();
+    }
+}
+
+#[test]
+fn registry_cycles() {
+    let mut registry = TypeRegistry::new();
+    registry.add::<Recursive>();
+    registry.add::<Even>();
+
+    assert_eq!(
+        names(registry.containers()),
+        ["Leaf", "Recursive", "Even", "Odd"]
+    );
+
+    let cycles: Vec<_> = registry.cycles().into_iter().map(names).collect();
+    assert_eq!(cycles, [vec!["Recursive"], vec!["Even", "Odd"]]);
+}
+
+fn path(name: &'static str, arguments: Vec<Type>) -> Type {
+    Type::Path(TypePath::from([PathSegment {
+        name,
+        arguments: arguments.into_iter().map(GenericArgument::Type).collect(),
+    }]))
+}
+
+fn named_field(name: &'static str, ty: Type) -> NamedField {
+    NamedField {
+        attributes: FieldAttributes::default(),
+        docs: Docs::default(),
+        name,
+        ty,
+    }
+}
+
+#[test]
+fn container_instantiate() {
+    // struct Page<'a, T, U = Vec<T>> {
+    //     items: &'a [T],
+    //     first: [T; 2],
+    //     rest: Wrapper<U>,
+    // }
+    let page = Container {
+        module_path: module_path!(),
+        attributes: Default::default(),
+        docs: Docs::default(),
+        item: Item::Struct(Struct {
+            visibility: Visibility::Pub,
+            name: "Page",
+            parameters: Vec::from([
+                GenericParameter::Lifetime("a"),
+                GenericParameter::Type(TypeParameter::from("T")),
+                GenericParameter::Type(TypeParameter {
+                    name: "U",
+                    bounds: Vec::new(),
+                    default: Some(Type::Sequence(SequenceType::from(path("T", Vec::new())))),
+                }),
+            ]),
+            content: StructContent::NamedFields(Vec::from([
+                named_field(
+                    "items",
+                    Type::Reference(ReferenceType {
+                        lifetime: Lifetime {
+                            name: String::from("a"),
+                        },
+                        ty: Box::new(Type::Slice(path("T", Vec::new()).into())),
+                    }),
+                ),
+                named_field(
+                    "first",
+                    Type::Array(ArrayType::with_length(path("T", Vec::new()), 2)),
+                ),
+                named_field("rest", path("Wrapper", Vec::from([path("U", Vec::new())]))),
+            ])),
+        }),
+    };
+
+    let arguments = Vec::from([path("User", Vec::new())]);
+    assert_eq!(page.instance_name(&arguments), "PageUser");
+    assert_eq!(
+        page.instantiate(String::from("PageUser"), arguments),
+        Ok(Instance {
+            name: Cow::Owned(String::from("PageUser")),
+            container: Container {
+                module_path: module_path!(),
+                attributes: Default::default(),
+                docs: Docs::default(),
+                item: Item::Struct(Struct {
+                    visibility: Visibility::Pub,
+                    name: "Page",
+                    parameters: Vec::from([GenericParameter::Lifetime("a")]),
+                    content: StructContent::NamedFields(Vec::from([
+                        named_field(
+                            "items",
+                            Type::Reference(ReferenceType {
+                                lifetime: Lifetime {
+                                    name: String::from("a"),
+                                },
+                                ty: Box::new(Type::Slice(path("User", Vec::new()).into())),
+                            }),
+                        ),
+                        named_field(
+                            "first",
+                            Type::Array(ArrayType::with_length(path("User", Vec::new()), 2)),
+                        ),
+                        named_field(
+                            "rest",
+                            path(
+                                "Wrapper",
+                                Vec::from([Type::Sequence(SequenceType::from(path(
+                                    "User",
+                                    Vec::new()
+                                )))]),
+                            ),
+                        ),
+                    ])),
+                }),
+            },
+        })
+    );
+}
+
+#[test]
+fn container_instantiate_enum() {
+    // enum Either<L, R> { Left(L), Right(R) }
+    let variant = |name, ty| Variant {
+        attributes: VariantAttributes::default(),
+        docs: Docs::default(),
+        name,
+        discriminant: None,
+        content: VariantContent::Unnamed(Vec::from([UnnamedField::from(ty)])),
+    };
+    let either = |name, left, right| Container {
+        module_path: module_path!(),
+        attributes: Default::default(),
+        docs: Docs::default(),
+        item: Item::Enum(Enum {
+            visibility: Visibility::Pub,
+            name,
+            parameters: Vec::new(),
+            variants: Vec::from([variant("Left", left), variant("Right", right)]),
+        }),
+    };
+    let mut generic = either("Either", path("L", Vec::new()), path("R", Vec::new()));
+    if let Item::Enum(e) = &mut generic.item {
+        e.parameters = Vec::from([
+            GenericParameter::Type(TypeParameter::from("L")),
+            GenericParameter::Type(TypeParameter::from("R")),
+        ]);
+    }
+
+    let arguments = Vec::from([
+        Type::Builtin(BuiltinType::U8),
+        path("Vec", Vec::from([Type::Builtin(BuiltinType::String)])),
+    ]);
+    assert_eq!(generic.instance_name(&arguments), "EitherU8VecString");
+    assert_eq!(
+        generic.instantiate("EitherU8VecString", arguments.clone()),
+        Ok(Instance {
+            name: Cow::Borrowed("EitherU8VecString"),
+            container: either("Either", arguments[0].clone(), arguments[1].clone()),
+        })
+    );
+}
+
+#[test]
+fn container_instantiate_missing_argument() {
+    let mut container = unit_struct("Either");
+    if let Item::Struct(s) = &mut container.item {
+        s.parameters = Vec::from([
+            GenericParameter::Type(TypeParameter::from("L")),
+            GenericParameter::Type(TypeParameter::from("R")),
+        ]);
+    }
+    let error = container
+        .clone()
+        .instantiate("Either", Vec::from([Type::Builtin(BuiltinType::U8)]))
+        .unwrap_err();
+    assert_eq!(
+        error,
+        InstantiationError::MissingArgument {
+            container: "Either",
+            parameter: "R",
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "`Either` requires an argument for the type parameter `R`"
+    );
+    assert_eq!(
+        container.instantiate("Either", vec![Type::Builtin(BuiltinType::U8); 3]),
+        Err(InstantiationError::TooManyArguments {
+            container: "Either",
+            parameters: 2,
+            arguments: 3,
+        })
+    );
+}
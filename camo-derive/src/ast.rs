@@ -4,33 +4,71 @@ use quote::quote;
 #[derive(Debug, Clone, PartialEq)]
 pub struct Container {
     pub serde: Option<SerdeContainerAttributes>,
+    pub camo: Option<CamoContainerAttributes>,
+    pub docs: Docs,
     pub item: Item,
 }
 
 impl Container {
     pub fn into_token_stream(self) -> TokenStream {
-        let attributes = match self.serde {
-            Some(serde) => serde.into_token_stream(),
-            None => quote! {
-                ::camo::core::ContainerAttributes {
-                    rename: ::core::option::Option::None,
-                    rename_all: ::core::option::Option::None,
-                    tag: ::core::option::Option::None,
-                    content: ::core::option::Option::None,
-                }
-            },
-        };
+        let serde = self.serde.unwrap_or_default().into_token_stream();
+        let camo = self.camo.unwrap_or_default().into_token_stream();
+        let docs = self.docs.into_token_stream();
         let item = self.item.into_token_stream();
 
         quote! {
             ::camo::core::Container {
-                attributes: #attributes,
+                module_path: ::core::module_path!(),
+                attributes: ::camo::core::ContainerAttributes {
+                    #serde
+                    #camo
+                },
+                docs: #docs,
                 item: #item,
             }
         }
     }
 }
 
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Docs {
+    pub lines: Vec<String>,
+    pub deprecated: Option<Deprecation>,
+}
+
+impl Docs {
+    fn into_token_stream(self) -> TokenStream {
+        let lines = self.lines;
+        let deprecated = match self.deprecated {
+            Some(deprecation) => {
+                let since = literal_attr_opt_to_token_stream(deprecation.since);
+                let note = literal_attr_opt_to_token_stream(deprecation.note);
+                quote! {
+                    ::core::option::Option::Some(::camo::core::Deprecation {
+                        since: #since,
+                        note: #note,
+                    })
+                }
+            }
+            None => quote!(::core::option::Option::None),
+        };
+        quote! {
+            ::camo::core::Docs {
+                lines: Vec::from([
+                    #(#lines),*
+                ]),
+                deprecated: #deprecated,
+            }
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Deprecation {
+    pub since: Option<String>,
+    pub note: Option<String>,
+}
+
 fn rename_rule_opt_to_token_stream(opt: Option<RenameRule>) -> TokenStream {
     if let Some(rule) = opt {
         let tokens = rule.into_token_stream();
@@ -48,27 +86,94 @@ fn literal_attr_opt_to_token_stream(opt: Option<String>) -> TokenStream {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+fn type_opt_to_token_stream(opt: Option<Type>) -> TokenStream {
+    if let Some(ty) = opt {
+        let tokens = ty.into_token_stream();
+        quote!(::core::option::Option::Some(#tokens))
+    } else {
+        quote!(::core::option::Option::None)
+    }
+}
+
+fn literal_list_to_token_stream(list: Vec<String>) -> TokenStream {
+    quote!(Vec::from([#(#list),*]))
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct SerdeContainerAttributes {
     pub rename_all: Option<RenameRule>,
-    pub rename: Option<RenameRule>,
+    pub rename_all_serialize: Option<RenameRule>,
+    pub rename_all_deserialize: Option<RenameRule>,
+    pub rename: Option<String>,
+    pub rename_serialize: Option<String>,
+    pub rename_deserialize: Option<String>,
     pub tag: Option<String>,
     pub content: Option<String>,
+    pub untagged: bool,
+    pub transparent: bool,
+    pub from: Option<Type>,
+    pub try_from: Option<Type>,
+    pub into: Option<Type>,
+    pub default: bool,
 }
 
 impl SerdeContainerAttributes {
     fn into_token_stream(self) -> TokenStream {
-        let rename = rename_rule_opt_to_token_stream(self.rename);
+        let rename = literal_attr_opt_to_token_stream(self.rename);
+        let rename_serialize = literal_attr_opt_to_token_stream(self.rename_serialize);
+        let rename_deserialize = literal_attr_opt_to_token_stream(self.rename_deserialize);
         let rename_all = rename_rule_opt_to_token_stream(self.rename_all);
+        let rename_all_serialize = rename_rule_opt_to_token_stream(self.rename_all_serialize);
+        let rename_all_deserialize = rename_rule_opt_to_token_stream(self.rename_all_deserialize);
         let tag = literal_attr_opt_to_token_stream(self.tag);
         let content = literal_attr_opt_to_token_stream(self.content);
+        let untagged = self.untagged;
+        let transparent = self.transparent;
+        let from = type_opt_to_token_stream(self.from);
+        let try_from = type_opt_to_token_stream(self.try_from);
+        let into = type_opt_to_token_stream(self.into);
+        let default = self.default;
         quote! {
-            ::camo::core::ContainerAttributes {
-                rename: #rename,
-                rename_all: #rename_all,
-                tag: #tag,
-                content: #content,
-            }
+            rename: #rename,
+            rename_serialize: #rename_serialize,
+            rename_deserialize: #rename_deserialize,
+            rename_all: #rename_all,
+            rename_all_serialize: #rename_all_serialize,
+            rename_all_deserialize: #rename_all_deserialize,
+            tag: #tag,
+            content: #content,
+            untagged: #untagged,
+            transparent: #transparent,
+            from: #from,
+            try_from: #try_from,
+            into: #into,
+            default: #default,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CamoContainerAttributes {
+    pub repr: bool,
+    pub rename: Option<String>,
+    pub export: Option<bool>,
+    /// Whether `serde` attributes that are not modeled are reported,
+    /// which only concerns the derive macro itself.
+    pub strict: bool,
+}
+
+impl CamoContainerAttributes {
+    fn into_token_stream(self) -> TokenStream {
+        let repr = self.repr;
+        let rename = literal_attr_opt_to_token_stream(self.rename);
+        let export = match self.export {
+            Some(export) => quote!(::core::option::Option::Some(#export)),
+            None => quote!(::core::option::Option::None),
+        };
+        quote! {
+            repr: #repr,
+            camo_rename: #rename,
+            export: #export,
         }
     }
 }
@@ -175,7 +280,8 @@ impl Struct {
 #[derive(Debug, Clone, PartialEq)]
 pub enum GenericParameter {
     Lifetime(String),
-    Type(String),
+    Type(TypeParameter),
+    Const(ConstParameter),
 }
 
 impl GenericParameter {
@@ -184,8 +290,66 @@ impl GenericParameter {
             Self::Lifetime(name) => {
                 quote!(::camo::core::GenericParameter::Lifetime(#name))
             }
-            Self::Type(name) => {
-                quote!(::camo::core::GenericParameter::Type(#name))
+            Self::Type(parameter) => {
+                let parameter = parameter.into_token_stream();
+                quote!(::camo::core::GenericParameter::Type(#parameter))
+            }
+            Self::Const(parameter) => {
+                let parameter = parameter.into_token_stream();
+                quote!(::camo::core::GenericParameter::Const(#parameter))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TypeParameter {
+    pub name: String,
+    pub bounds: Vec<TypePath>,
+    pub default: Option<Type>,
+}
+
+impl TypeParameter {
+    fn into_token_stream(self) -> TokenStream {
+        let name = self.name;
+        let bounds: Vec<_> = self
+            .bounds
+            .into_iter()
+            .map(TypePath::into_token_stream)
+            .collect();
+        let default = match self.default {
+            Some(ty) => {
+                let ty = ty.into_token_stream();
+                quote!(::core::option::Option::Some(#ty))
+            }
+            None => quote!(::core::option::Option::None),
+        };
+        quote! {
+            ::camo::core::TypeParameter {
+                name: #name,
+                bounds: Vec::from([
+                    #(#bounds),*
+                ]),
+                default: #default,
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConstParameter {
+    pub name: String,
+    pub ty: Type,
+}
+
+impl ConstParameter {
+    fn into_token_stream(self) -> TokenStream {
+        let name = self.name;
+        let ty = self.ty.into_token_stream();
+        quote! {
+            ::camo::core::ConstParameter {
+                name: #name,
+                ty: #ty,
             }
         }
     }
@@ -194,7 +358,8 @@ impl GenericParameter {
 #[derive(Debug, Clone, PartialEq)]
 pub enum StructContent {
     NamedFields(Vec<NamedField>),
-    UnnamedField(UnnamedField),
+    UnnamedFields(Vec<UnnamedField>),
+    Unit,
 }
 
 impl StructContent {
@@ -213,28 +378,49 @@ impl StructContent {
                     )
                 }
             }
-            StructContent::UnnamedField(field) => {
-                let field = field.into_token_stream();
+            StructContent::UnnamedFields(fields) => {
+                let fields: Vec<_> = fields
+                    .into_iter()
+                    .map(|field| field.into_token_stream())
+                    .collect();
                 quote! {
-                    ::camo::core::StructContent::UnnamedField(#field)
+                    ::camo::core::StructContent::UnnamedFields(
+                        Vec::from([
+                            #(#fields),*
+                        ])
+                    )
                 }
             }
+            StructContent::Unit => quote! {
+                ::camo::core::StructContent::Unit
+            },
         }
     }
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct NamedField {
+    pub serde: Option<SerdeFieldAttributes>,
+    pub camo: Option<CamoFieldAttributes>,
+    pub docs: Docs,
     pub name: String,
     pub ty: Type,
 }
 
 impl NamedField {
     pub fn into_token_stream(self) -> TokenStream {
+        let serde = self.serde.unwrap_or_default().into_token_stream();
+        let camo = self.camo.unwrap_or_default().into_token_stream();
+        let docs = self.docs.into_token_stream();
         let name = self.name;
         let ty = self.ty.into_token_stream();
         quote! {
             ::camo::core::NamedField {
+                attributes: ::camo::core::FieldAttributes {
+                    #serde
+                    #camo
+                },
+                docs: #docs,
                 name: #name,
                 ty: #ty,
             }
@@ -242,16 +428,108 @@ impl NamedField {
     }
 }
 
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct SerdeFieldAttributes {
+    pub rename: Option<String>,
+    pub rename_serialize: Option<String>,
+    pub rename_deserialize: Option<String>,
+    pub aliases: Vec<String>,
+    pub skip: bool,
+    pub skip_serializing: bool,
+    pub skip_deserializing: bool,
+    pub default: bool,
+    pub skip_serializing_if: Option<String>,
+    pub flatten: bool,
+}
+
+impl SerdeFieldAttributes {
+    fn into_token_stream(self) -> TokenStream {
+        let rename = literal_attr_opt_to_token_stream(self.rename);
+        let rename_serialize = literal_attr_opt_to_token_stream(self.rename_serialize);
+        let rename_deserialize = literal_attr_opt_to_token_stream(self.rename_deserialize);
+        let aliases = literal_list_to_token_stream(self.aliases);
+        let skip = self.skip;
+        let skip_serializing = self.skip_serializing;
+        let skip_deserializing = self.skip_deserializing;
+        let default = self.default;
+        let skip_serializing_if = literal_attr_opt_to_token_stream(self.skip_serializing_if);
+        let flatten = self.flatten;
+        quote! {
+            rename: #rename,
+            rename_serialize: #rename_serialize,
+            rename_deserialize: #rename_deserialize,
+            aliases: #aliases,
+            skip: #skip,
+            skip_serializing: #skip_serializing,
+            skip_deserializing: #skip_deserializing,
+            default: #default,
+            skip_serializing_if: #skip_serializing_if,
+            flatten: #flatten,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CamoFieldAttributes {
+    pub rename: Option<String>,
+    pub skip: bool,
+    pub foreign: Option<String>,
+    /// The Rust type given by `as = "..."`, which describes the field
+    /// in place of its own type.
+    pub as_type: Option<syn::Type>,
+    pub typescript: Option<String>,
+}
+
+impl CamoFieldAttributes {
+    fn into_token_stream(self) -> TokenStream {
+        let rename = literal_attr_opt_to_token_stream(self.rename);
+        let skip = self.skip;
+        let foreign = self.foreign.is_some();
+        let typescript = literal_attr_opt_to_token_stream(self.typescript);
+        quote! {
+            camo_rename: #rename,
+            camo_skip: #skip,
+            foreign: #foreign,
+            typescript: #typescript,
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct UnnamedField {
+    pub serde: Option<SerdeFieldAttributes>,
+    pub camo: Option<CamoFieldAttributes>,
     pub ty: Type,
+    /// Whether the type is described as `()` if it turns out to be a unit
+    /// struct, as for the newtype variants of internally tagged enums, where
+    /// `serde` only emits the tag for such a struct.
+    pub collapse_unit_struct: bool,
 }
 
 impl UnnamedField {
     pub fn into_token_stream(self) -> TokenStream {
-        let ty = self.ty.into_token_stream();
+        let serde = self.serde.unwrap_or_default().into_token_stream();
+        let camo = self.camo.unwrap_or_default().into_token_stream();
+        let ty = match self.ty {
+            Type::Path(path) if self.collapse_unit_struct => {
+                let syn = path.ty.clone();
+                let ty = Type::Path(path).into_token_stream();
+                quote! {
+                    if (&::camo::core::__private::Probe::<#syn>::new()).is_unit_struct() {
+                        ::camo::core::Type::Tuple(::camo::core::TupleType::unit())
+                    } else {
+                        #ty
+                    }
+                }
+            }
+            ty => ty.into_token_stream(),
+        };
         quote! {
             ::camo::core::UnnamedField {
+                attributes: ::camo::core::FieldAttributes {
+                    #serde
+                    #camo
+                },
                 ty: #ty,
             }
         }
@@ -299,27 +577,33 @@ impl Enum {
 #[derive(Debug, Clone, PartialEq)]
 pub struct Variant {
     pub serde: Option<SerdeVariantAttributes>,
+    pub camo: Option<CamoVariantAttributes>,
+    pub docs: Docs,
     pub name: String,
+    pub discriminant: Option<i128>,
     pub content: VariantContent,
 }
 
 impl Variant {
     fn into_token_stream(self) -> TokenStream {
-        let attributes = match self.serde {
-            Some(serde) => serde.into_token_stream(),
-            None => quote! {
-                ::camo::core::VariantAttributes {
-                    rename: ::core::option::Option::None,
-                    rename_all: ::core::option::Option::None,
-                }
-            },
-        };
+        let serde = self.serde.unwrap_or_default().into_token_stream();
+        let camo = self.camo.unwrap_or_default().into_token_stream();
+        let docs = self.docs.into_token_stream();
         let name = self.name;
+        let discriminant = match self.discriminant {
+            Some(value) => quote!(::core::option::Option::Some(#value)),
+            None => quote!(::core::option::Option::None),
+        };
         let content = self.content.into_token_stream();
         quote! {
             ::camo::core::Variant {
-                attributes: #attributes,
+                attributes: ::camo::core::VariantAttributes {
+                    #serde
+                    #camo
+                },
+                docs: #docs,
                 name: #name,
+                discriminant: #discriminant,
                 content: #content,
             }
         }
@@ -328,19 +612,61 @@ impl Variant {
 
 #[derive(Debug, Default, Clone, PartialEq)]
 pub struct SerdeVariantAttributes {
-    pub rename: Option<RenameRule>,
+    pub rename: Option<String>,
+    pub rename_serialize: Option<String>,
+    pub rename_deserialize: Option<String>,
+    pub aliases: Vec<String>,
     pub rename_all: Option<RenameRule>,
+    pub rename_all_serialize: Option<RenameRule>,
+    pub rename_all_deserialize: Option<RenameRule>,
+    pub skip: bool,
+    pub skip_serializing: bool,
+    pub skip_deserializing: bool,
+    pub untagged: bool,
 }
 
 impl SerdeVariantAttributes {
     fn into_token_stream(self) -> TokenStream {
-        let rename = rename_rule_opt_to_token_stream(self.rename);
+        let rename = literal_attr_opt_to_token_stream(self.rename);
+        let rename_serialize = literal_attr_opt_to_token_stream(self.rename_serialize);
+        let rename_deserialize = literal_attr_opt_to_token_stream(self.rename_deserialize);
+        let aliases = literal_list_to_token_stream(self.aliases);
         let rename_all = rename_rule_opt_to_token_stream(self.rename_all);
+        let rename_all_serialize = rename_rule_opt_to_token_stream(self.rename_all_serialize);
+        let rename_all_deserialize = rename_rule_opt_to_token_stream(self.rename_all_deserialize);
+        let skip = self.skip;
+        let skip_serializing = self.skip_serializing;
+        let skip_deserializing = self.skip_deserializing;
+        let untagged = self.untagged;
         quote! {
-            ::camo::core::VariantAttributes {
-                rename: #rename,
-                rename_all: #rename_all,
-            }
+            rename: #rename,
+            rename_serialize: #rename_serialize,
+            rename_deserialize: #rename_deserialize,
+            aliases: #aliases,
+            rename_all: #rename_all,
+            rename_all_serialize: #rename_all_serialize,
+            rename_all_deserialize: #rename_all_deserialize,
+            skip: #skip,
+            skip_serializing: #skip_serializing,
+            skip_deserializing: #skip_deserializing,
+            untagged: #untagged,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct CamoVariantAttributes {
+    pub rename: Option<String>,
+    pub skip: bool,
+}
+
+impl CamoVariantAttributes {
+    fn into_token_stream(self) -> TokenStream {
+        let rename = literal_attr_opt_to_token_stream(self.rename);
+        let skip = self.skip;
+        quote! {
+            camo_rename: #rename,
+            camo_skip: #skip,
         }
     }
 }
@@ -348,7 +674,7 @@ impl SerdeVariantAttributes {
 #[derive(Debug, Clone, PartialEq)]
 pub enum VariantContent {
     Unit,
-    Unnamed(Type),
+    Unnamed(Vec<UnnamedField>),
     Named(Vec<NamedField>),
 }
 
@@ -358,10 +684,12 @@ impl VariantContent {
             VariantContent::Unit => quote! {
                 ::camo::core::VariantContent::Unit
             },
-            VariantContent::Unnamed(ty) => {
-                let ty = ty.into_token_stream();
+            VariantContent::Unnamed(fields) => {
+                let fields: Vec<_> = fields.into_iter().map(|f| f.into_token_stream()).collect();
                 quote! {
-                    ::camo::core::VariantContent::Unnamed(#ty)
+                    ::camo::core::VariantContent::Unnamed(Vec::from([
+                        #(#fields),*
+                    ]))
                 }
             }
             VariantContent::Named(fields) => {
@@ -382,15 +710,23 @@ pub enum Type {
     Reference(ReferenceType),
     Slice(SliceType),
     Array(ArrayType),
+    Tuple(TupleType),
+    /// A type given by `#[camo(foreign = "...")]`, which is not a Rust type
+    /// and is therefore described as a plain path.
+    Foreign(Vec<String>),
+    /// The type of a field whose Rust type is not described, because
+    /// it is overridden with `#[camo(typescript = "...")]` or skipped.
+    Opaque,
 }
 
 impl Type {
     pub fn into_token_stream(self) -> TokenStream {
         match self {
             Type::Path(ty) => {
+                let syn = ty.ty.clone();
                 let content = ty.into_token_stream();
                 quote! {
-                    ::camo::core::Type::Path(#content)
+                    (&::camo::core::__private::Probe::<#syn>::new()).describe(#content)
                 }
             }
             Type::Reference(ty) => {
@@ -411,6 +747,29 @@ impl Type {
                     ::camo::core::Type::Array(#content)
                 }
             }
+            Type::Tuple(ty) => {
+                let content = ty.into_token_stream();
+                quote! {
+                    ::camo::core::Type::Tuple(#content)
+                }
+            }
+            Type::Foreign(segments) => {
+                quote! {
+                    ::camo::core::Type::Path(::camo::core::TypePath {
+                        segments: Vec::from([
+                            #(
+                                ::camo::core::PathSegment {
+                                    name: #segments,
+                                    arguments: Vec::new(),
+                                }
+                            ),*
+                        ])
+                    })
+                }
+            }
+            Type::Opaque => quote! {
+                ::camo::core::Type::Opaque
+            },
         }
     }
 }
@@ -453,6 +812,9 @@ impl Lifetime {
 #[derive(Debug, Clone, PartialEq)]
 pub struct TypePath {
     pub segments: Vec<PathSegment>,
+    /// The path as it was written, which is used to look up
+    /// a `Descriptor` for the type it refers to.
+    pub ty: syn::TypePath,
 }
 
 impl TypePath {
@@ -536,17 +898,43 @@ impl SliceType {
 }
 
 #[derive(Debug, Clone, PartialEq)]
-pub struct ArrayType(pub Box<Type>);
+pub struct ArrayType {
+    pub ty: Box<Type>,
+    pub length: Option<usize>,
+}
 
-impl From<Type> for ArrayType {
-    fn from(value: Type) -> Self {
-        Self(Box::new(value))
+impl ArrayType {
+    fn into_token_stream(self) -> TokenStream {
+        let ty = self.ty.into_token_stream();
+        let length = match self.length {
+            Some(length) => quote!(::core::option::Option::Some(#length)),
+            None => quote!(::core::option::Option::None),
+        };
+        quote! {
+            ::camo::core::ArrayType {
+                ty: Box::new(#ty),
+                length: #length,
+            }
+        }
     }
 }
 
-impl ArrayType {
+#[derive(Debug, Clone, PartialEq)]
+pub struct TupleType(pub Vec<Type>);
+
+impl From<Vec<Type>> for TupleType {
+    fn from(value: Vec<Type>) -> Self {
+        Self(value)
+    }
+}
+
+impl TupleType {
     fn into_token_stream(self) -> TokenStream {
-        let ty = self.0.into_token_stream();
-        quote!(::camo::core::ArrayType(Box::new(#ty)))
+        let elements: Vec<_> = self.0.into_iter().map(Type::into_token_stream).collect();
+        quote! {
+            ::camo::core::TupleType(Vec::from([
+                #(#elements),*
+            ]))
+        }
     }
 }
@@ -1,10 +1,13 @@
 use proc_macro2::{Ident, Span, TokenStream};
 use quote::quote;
+use syn::meta::ParseNestedMeta;
+use syn::punctuated::Punctuated;
 use syn::spanned::Spanned;
 use syn::{
-    AttrStyle, Attribute, Data, DataEnum, DataStruct, DeriveInput, Fields, GenericArgument,
-    GenericParam, Generics, LitStr, Meta, MetaList, Path, PathArguments, PathSegment, Token,
-    TypePath, TypeReference, Variant, Visibility,
+    parenthesized, token, AttrStyle, Attribute, Data, DataEnum, DataStruct, DeriveInput, Expr,
+    ExprLit, ExprUnary, Field, Fields, FieldsUnnamed, GenericArgument, GenericParam, Generics, Lit,
+    LitBool, LitStr, Meta, MetaList, Path, PathArguments, PathSegment, Token, TraitBoundModifier,
+    TypeParamBound, TypePath, TypeReference, UnOp, Variant, Visibility, WherePredicate,
 };
 
 use crate::ast;
@@ -12,13 +15,7 @@ use crate::ast;
 pub fn derive(input: DeriveInput) -> TokenStream {
     match Impl::from_input(input) {
         Ok(v) => v.into_token_stream(),
-        Err(error) => {
-            if let ErrorKind::Syn(err) = error.kind {
-                err.into_compile_error()
-            } else {
-                syn::Error::new(error.span, error.kind.message()).into_compile_error()
-            }
-        }
+        Err(error) => error.into_syn_error().into_compile_error(),
     }
 }
 
@@ -29,12 +26,11 @@ pub struct Error {
 
 pub enum ErrorKind {
     Union,
-    UnitStruct,
     GenericBounds,
     ConstGenerics,
-    ExplicicitDiscriminant,
-    EnumMultipleUnnamedFields,
-    StructMultipleUnnamedFields,
+    InvalidDiscriminant,
+    ReprNotEnum,
+    ReprNonUnitVariant,
     FunctionTypes,
     Macros,
     SelfQualifiedTypes,
@@ -43,30 +39,123 @@ pub enum ErrorKind {
     InvalidRenameRule,
     VisibilityRestricted,
     UnknownGenericArgument,
+    UnknownAttribute,
+    InternallyTaggedTupleVariant,
 }
 
+impl Error {
+    /// Reports `other` along with this error, in the style of `syn::Error::combine`.
+    fn combine(self, other: Error) -> Error {
+        let span = self.span;
+        let mut error = self.into_syn_error();
+        error.combine(other.into_syn_error());
+        Error {
+            kind: ErrorKind::Syn(error),
+            span,
+        }
+    }
+
+    /// Names the construct in which the error occurred (as in "field `foo`
+    /// of struct `Bar`"), along with a hint at the `#[camo]` attribute that
+    /// works around it, if there is one.
+    ///
+    /// Errors from parsing attributes already point at the attribute,
+    /// and are left as they are.
+    fn within(self, construct: &str, hint: Option<&str>) -> Error {
+        match self.kind {
+            ErrorKind::Syn(_) => self,
+            kind => {
+                let mut message = format!("{} (in {})", kind.message(), construct);
+                if let Some(hint) = hint {
+                    message.push_str("\nhelp: ");
+                    message.push_str(hint);
+                }
+                Error {
+                    kind: ErrorKind::Syn(syn::Error::new(self.span, message)),
+                    span: self.span,
+                }
+            }
+        }
+    }
+
+    fn into_syn_error(self) -> syn::Error {
+        match self.kind {
+            ErrorKind::Syn(error) => error,
+            kind => syn::Error::new(self.span, kind.message()),
+        }
+    }
+}
+
+/// Combines two results, reporting the errors of both.
+fn join<A, B>(a: Result<A, Error>, b: Result<B, Error>) -> Result<(A, B), Error> {
+    match (a, b) {
+        (Ok(a), Ok(b)) => Ok((a, b)),
+        (Err(a), Err(b)) => Err(a.combine(b)),
+        (Err(error), Ok(_)) | (Ok(_), Err(error)) => Err(error),
+    }
+}
+
+/// Collects the results, reporting every error rather than only the first.
+fn collect_all<T, I>(results: I) -> Result<Vec<T>, Error>
+where
+    I: IntoIterator<Item = Result<T, Error>>,
+{
+    let mut values = Vec::new();
+    let mut error: Option<Error> = None;
+    for result in results {
+        match result {
+            Ok(value) => values.push(value),
+            Err(next) => {
+                error = Some(match error {
+                    Some(error) => error.combine(next),
+                    None => next,
+                })
+            }
+        }
+    }
+    match error {
+        Some(error) => Err(error),
+        None => Ok(values),
+    }
+}
+
+/// How to work around a field type that `camo` does not support.
+const FIELD_TYPE_HINT: &str = "describe the field with `#[camo(as = \"...\")]`, \
+    `#[camo(foreign = \"...\")]` or `#[camo(typescript = \"...\")]` instead, \
+    or leave it out with `#[camo(skip)]`";
+
+/// How to work around a visibility that `camo` does not support.
+const VISIBILITY_HINT: &str =
+    "choose whether the definition is exported with `#[camo(export = true)]` \
+    or `#[camo(export = false)]` instead";
+
 impl ErrorKind {
     pub fn message(&self) -> &'static str {
         match self {
             Self::Union => "`camo` does not support unions",
-            Self::UnitStruct => "`camo` does not support unit structs",
-            Self::GenericBounds => "`camo` does not support generic bounds",
-            Self::ConstGenerics => "`camo` does not support const generics",
-            Self::ExplicicitDiscriminant => "`camo` does not support explicit discriminants",
-            Self::EnumMultipleUnnamedFields => {
-                "`camo` does not support multiple unnamed fields in enums"
-            }
-            Self::StructMultipleUnnamedFields => {
-                "`camo` does not support multiple unnamed fields in structs"
+            Self::GenericBounds => "`camo` does not support bounds in generic arguments",
+            Self::ConstGenerics => "`camo` does not support const generic arguments",
+            Self::InvalidDiscriminant => {
+                "`camo` only supports integer literals as explicit discriminants"
             }
+            Self::ReprNotEnum => "`camo(repr)` can only be used on enums",
+            Self::ReprNonUnitVariant => "`camo(repr)` requires all variants to be unit variants",
             Self::FunctionTypes => "`camo` does not support function types",
             Self::Macros => "`camo` does not support macros",
             Self::SelfQualifiedTypes => "`camo` does not support self-qualified types in paths",
             Self::MiscTypes => "`camo` does not support this type",
             Self::Syn(_) => "`camo`: failed to parse attribute",
-            Self::InvalidRenameRule => "`camo`: invalid rename rule",
+            Self::InvalidRenameRule => {
+                "`camo`: invalid rename rule, expected one of `lowercase`, `UPPERCASE`, \
+                `PascalCase`, `camelCase`, `snake_case`, `SCREAMING_SNAKE_CASE`, \
+                `kebab-case` or `SCREAMING-KEBAB-CASE`"
+            }
             Self::VisibilityRestricted => "`camo` does not support restricted visibility",
             Self::UnknownGenericArgument => "`camo` does not support this generic argument",
+            Self::UnknownAttribute => "`camo`: unknown attribute",
+            Self::InternallyTaggedTupleVariant => {
+                "`#[serde(tag = \"...\")]` cannot be used with tuple variants"
+            }
         }
     }
 }
@@ -74,6 +163,8 @@ impl ErrorKind {
 struct Impl {
     name: Ident,
     generics: Generics,
+    dependencies: Vec<syn::Type>,
+    resolutions: Vec<(ast::TypePath, syn::Type)>,
     container: ast::Container,
 }
 
@@ -81,10 +172,14 @@ impl Impl {
     fn from_input(input: DeriveInput) -> Result<Self, Error> {
         let name = input.ident.clone();
         let generics = input.generics.clone();
+        let dependencies = dependencies(&input.data);
         let container = ast::Container::from_input(input)?;
+        let resolutions = resolutions(&dependencies);
         Ok(Self {
             name,
             generics,
+            dependencies,
+            resolutions,
             container,
         })
     }
@@ -93,41 +188,433 @@ impl Impl {
         let Self {
             name,
             generics,
+            dependencies,
+            resolutions,
             container,
         } = self;
         let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+        let identity = name.to_string();
         let container = container.into_token_stream();
+        let dependencies = if dependencies.is_empty() {
+            TokenStream::new()
+        } else {
+            quote! {
+                fn dependencies(dependencies: &mut ::camo::core::Dependencies) {
+                    use ::camo::core::__private::{CamoProbe as _, FallbackProbe as _};
+                    #(
+                        (&::camo::core::__private::Probe::<#dependencies>::new())
+                            .add_to(dependencies);
+                    )*
+                }
+            }
+        };
+        let resolve = if resolutions.is_empty() {
+            TokenStream::new()
+        } else {
+            let (paths, types): (Vec<_>, Vec<_>) = resolutions
+                .into_iter()
+                .map(|(path, ty)| (path.into_token_stream(), ty))
+                .unzip();
+            quote! {
+                fn resolve(
+                    path: &::camo::core::TypePath,
+                ) -> ::core::option::Option<::camo::core::TypeIdentity> {
+                    #[allow(unused_imports)]
+                    use ::camo::core::__private::{
+                        CamoProbe as _, DescriptorProbe as _, FallbackProbe as _,
+                    };
+                    #(
+                        if *path == (#paths) {
+                            return (&::camo::core::__private::Probe::<#types>::new()).identity();
+                        }
+                    )*
+                    ::core::option::Option::None
+                }
+            }
+        };
         quote! {
             #[automatically_derived]
+            #[allow(deprecated)]
             impl #impl_generics ::camo::Camo for #name #ty_generics #where_clause {
                 fn camo() -> ::camo::core::Container {
+                    #[allow(unused_imports)]
+                    use ::camo::core::__private::{
+                        CamoProbe as _, DescriptorProbe as _, FallbackProbe as _,
+                    };
                     #container
                 }
+
+                fn identity() -> ::camo::core::TypeIdentity {
+                    ::camo::core::TypeIdentity {
+                        module_path: ::core::module_path!(),
+                        name: #identity,
+                    }
+                }
+
+                #dependencies
+
+                #resolve
+            }
+        }
+    }
+}
+
+/// Collects the types that may refer to other type definitions,
+/// i. e. every field type, along with the types nested within them.
+///
+/// Whether a type implements `Camo` is decided in the generated code,
+/// so types that do not are silently ignored.
+fn dependencies(data: &Data) -> Vec<syn::Type> {
+    let fields: Vec<&Field> = match data {
+        Data::Struct(data) => data.fields.iter().collect(),
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .filter(|variant| {
+                !ast::CamoVariantAttributes::from_attributes(&variant.attrs)
+                    .ok()
+                    .flatten()
+                    .is_some_and(|camo| camo.skip)
+            })
+            .flat_map(|variant| variant.fields.iter())
+            .collect(),
+        Data::Union(_) => Vec::new(),
+    };
+    let mut types = Vec::new();
+    for field in fields {
+        // Fields that are left out of the definitions, or described
+        // by a foreign or TypeScript type, do not refer to other definitions
+        let camo = ast::CamoFieldAttributes::from_attributes(&field.attrs, field.ident.is_some())
+            .ok()
+            .flatten()
+            .unwrap_or_default();
+        if let Some(ty) = &camo.as_type {
+            collect_types(ty, &mut types);
+            continue;
+        }
+        if camo.skip || camo.foreign.is_some() || camo.typescript.is_some() {
+            continue;
+        }
+        collect_types(&field.ty, &mut types);
+    }
+    types
+}
+
+/// Pairs each path type among the dependencies with its description,
+/// so that the generated code can resolve a path to the type it refers to.
+fn resolutions(dependencies: &[syn::Type]) -> Vec<(ast::TypePath, syn::Type)> {
+    dependencies
+        .iter()
+        .filter_map(|ty| match ty {
+            syn::Type::Path(path) => ast::TypePath::from_type_path(path.clone())
+                .ok()
+                .map(|path| (path, ty.clone())),
+            _ => None,
+        })
+        .collect()
+}
+
+fn collect_types(ty: &syn::Type, types: &mut Vec<syn::Type>) {
+    match ty {
+        syn::Type::Path(path) => {
+            if path.qself.is_some() {
+                return;
+            }
+            types.push(ty.clone());
+            for segment in &path.path.segments {
+                if let PathArguments::AngleBracketed(arguments) = &segment.arguments {
+                    for argument in &arguments.args {
+                        if let GenericArgument::Type(ty) = argument {
+                            collect_types(ty, types);
+                        }
+                    }
+                }
             }
         }
+        syn::Type::Reference(reference) => collect_types(&reference.elem, types),
+        syn::Type::Slice(slice) => collect_types(&slice.elem, types),
+        syn::Type::Array(array) => collect_types(&array.elem, types),
+        syn::Type::Tuple(tuple) => {
+            for ty in &tuple.elems {
+                collect_types(ty, types);
+            }
+        }
+        syn::Type::Paren(paren) => collect_types(&paren.elem, types),
+        syn::Type::Group(group) => collect_types(&group.elem, types),
+        _ => {}
     }
 }
 
 impl ast::Container {
     fn from_input(input: DeriveInput) -> Result<Self, Error> {
-        let serde = input
-            .attrs
+        let serde = SerdeAttributeList::from_attributes(&input.attrs)
+            .map(ast::SerdeContainerAttributes::from_lists)
+            .transpose();
+
+        let camo = ast::CamoContainerAttributes::from_attributes(&input.attrs, &input.data);
+
+        let strict = cfg!(feature = "strict") || matches!(&camo, Ok(Some(camo)) if camo.strict);
+        let checked = if strict { check_strict(&input) } else { Ok(()) };
+
+        let internally_tagged = matches!(
+            &serde,
+            Ok(Some(serde)) if serde.tag.is_some() && serde.content.is_none() && !serde.untagged
+        );
+        let tagged = if internally_tagged {
+            check_internally_tagged(&input)
+        } else {
+            Ok(())
+        };
+
+        let docs = ast::Docs::from_attributes(&input.attrs);
+        let export = match &camo {
+            Ok(Some(camo)) => camo.export,
+            _ => None,
+        };
+        let item = ast::Item::from_input(input, export);
+
+        let (((((serde, camo), ()), ()), docs), mut item) = join(
+            join(join(join(join(serde, camo), checked), tagged), docs),
+            item,
+        )?;
+
+        if internally_tagged {
+            if let ast::Item::Enum(ty) = &mut item {
+                for variant in &mut ty.variants {
+                    if let ast::VariantContent::Unnamed(fields) = &mut variant.content {
+                        if let [field] = fields.as_mut_slice() {
+                            field.collapse_unit_struct = true;
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(Self {
+            serde,
+            camo,
+            docs,
+            item,
+        })
+    }
+}
+
+impl ast::Docs {
+    fn from_attributes(attributes: &[Attribute]) -> Result<Self, Error> {
+        let mut text = Vec::new();
+        let mut deprecated = None;
+        for attribute in attributes {
+            if attribute.path().is_ident("doc") {
+                // `#[doc(hidden)]` and the like are not documentation
+                if let Meta::NameValue(meta) = &attribute.meta {
+                    if let Expr::Lit(ExprLit {
+                        lit: Lit::Str(literal),
+                        ..
+                    }) = &meta.value
+                    {
+                        text.push(literal.value());
+                    }
+                }
+            } else if attribute.path().is_ident("deprecated") {
+                deprecated = Some(ast::Deprecation::from_meta(&attribute.meta)?);
+            }
+        }
+        Ok(Self {
+            lines: doc_lines(&text),
+            deprecated,
+        })
+    }
+}
+
+/// Splits documentation into lines, removing the indentation that all
+/// lines have in common (like the space after `///`), along with any
+/// blank lines at the start or end.
+fn doc_lines(text: &[String]) -> Vec<String> {
+    let lines: Vec<&str> = text
+        .iter()
+        .flat_map(|text| text.split('\n'))
+        .map(str::trim_end)
+        .collect();
+    let indentation = lines
+        .iter()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    let start = lines.iter().position(|line| !line.is_empty());
+    let end = lines.iter().rposition(|line| !line.is_empty());
+    match (start, end) {
+        (Some(start), Some(end)) => lines[start..=end]
             .iter()
-            .find_map(SerdeAttributeList::from_attribute)
-            .transpose()?
-            .map(ast::SerdeContainerAttributes::from_list)
-            .transpose()?;
+            .map(|line| line.get(indentation..).unwrap_or("").to_string())
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+impl ast::Deprecation {
+    fn from_meta(meta: &Meta) -> Result<Self, Error> {
+        let mut deprecation = Self::default();
+        match meta {
+            // `#[deprecated]`
+            Meta::Path(_) => {}
+            // `#[deprecated = "note"]`
+            Meta::NameValue(meta) => {
+                if let Expr::Lit(ExprLit {
+                    lit: Lit::Str(literal),
+                    ..
+                }) = &meta.value
+                {
+                    deprecation.note = Some(literal.value());
+                }
+            }
+            // `#[deprecated(since = "1.0.0", note = "...")]`
+            Meta::List(list) => {
+                list.parse_nested_meta(|meta| {
+                    let value: LitStr = meta.value()?.parse()?;
+                    if meta.path.is_ident("since") {
+                        deprecation.since = Some(value.value());
+                    } else if meta.path.is_ident("note") {
+                        deprecation.note = Some(value.value());
+                    }
+                    Ok(())
+                })
+                .map_err(|error| Error {
+                    span: error.span(),
+                    kind: ErrorKind::Syn(error),
+                })?;
+            }
+        }
+        Ok(deprecation)
+    }
+}
 
-        let item = ast::Item::from_input(input)?;
+/// Reports the tuple variants of an internally tagged enum, which `serde`
+/// does not support (and which are not valid for `serde_derive` either).
+fn check_internally_tagged(input: &DeriveInput) -> Result<(), Error> {
+    let variants = match &input.data {
+        Data::Enum(data) => &data.variants,
+        _ => return Ok(()),
+    };
+    collect_all(variants.iter().map(|variant| {
+        let untagged = SerdeAttributeList::from_attributes(&variant.attrs)
+            .map(ast::SerdeVariantAttributes::from_lists)
+            .and_then(Result::ok)
+            .is_some_and(|serde| serde.untagged);
+        match &variant.fields {
+            Fields::Unnamed(fields) if fields.unnamed.len() != 1 && !untagged => Err(Error {
+                kind: ErrorKind::InternallyTaggedTupleVariant,
+                span: variant.ident.span(),
+            }
+            .within(
+                &format!("variant `{}::{}`", input.ident, variant.ident),
+                None,
+            )),
+            _ => Ok(()),
+        }
+    }))?;
+    Ok(())
+}
 
-        Ok(Self { serde, item })
+fn check_repr(data: &Data, span: Span) -> Result<(), Error> {
+    match data {
+        Data::Enum(data) => {
+            for variant in &data.variants {
+                if !matches!(variant.fields, Fields::Unit) {
+                    return Err(Error {
+                        kind: ErrorKind::ReprNonUnitVariant,
+                        span: variant.fields.span(),
+                    });
+                }
+            }
+            Ok(())
+        }
+        Data::Struct(_) => Err(Error {
+            kind: ErrorKind::ReprNotEnum,
+            span,
+        }),
+        // Reported when the item itself is parsed
+        Data::Union(_) => Ok(()),
     }
 }
 
 struct SerdeAttributeList(MetaList);
 
 impl SerdeAttributeList {
-    fn from_attribute(attr: &Attribute) -> Option<Result<Self, Error>> {
+    fn into_attributes(self) -> Result<Vec<SerdeAttribute>, Error> {
+        let Self(meta) = self;
+
+        let mut rules = Vec::new();
+
+        let result = meta.parse_nested_meta(|meta| {
+            if meta.input.peek(Token![=]) {
+                let value = meta.value()?;
+                let lit: LitStr = value.parse()?;
+
+                match SerdeAttribute::from_pair(&meta.path, lit) {
+                    Ok(Some(attr)) => {
+                        rules.push(attr);
+                        Ok(())
+                    }
+                    Ok(None) => Ok(()),
+                    Err(error) => Err(syn::Error::new(error.span, error.kind.message())),
+                }
+            } else if meta.path.is_ident("rename") || meta.path.is_ident("rename_all") {
+                // Direction-specific names, as in `rename(serialize = "...")`
+                let outer = meta.path.clone();
+                meta.parse_nested_meta(|meta| {
+                    let value = meta.value()?;
+                    let lit: LitStr = value.parse()?;
+
+                    match SerdeAttribute::from_directional_pair(&outer, &meta.path, lit) {
+                        Ok(Some(attr)) => {
+                            rules.push(attr);
+                            Ok(())
+                        }
+                        Ok(None) => Ok(()),
+                        Err(error) => Err(syn::Error::new(error.span, error.kind.message())),
+                    }
+                })
+            } else if meta.input.peek(token::Paren) {
+                // Other nested lists are not understood; skip their content
+                let content;
+                parenthesized!(content in meta.input);
+                content.parse::<TokenStream>()?;
+                Ok(())
+            } else {
+                rules.extend(SerdeAttribute::from_path(&meta.path));
+                Ok(())
+            }
+        });
+
+        if let Err(error) = result {
+            return Err(Error {
+                kind: ErrorKind::Syn(error),
+                span: meta.span(),
+            });
+        }
+
+        Ok(rules)
+    }
+
+    /// The attributes of every `#[serde(...)]` attribute in the list, in order.
+    fn into_all_attributes(lists: Vec<Self>) -> Result<Vec<SerdeAttribute>, Error> {
+        let rules = collect_all(lists.into_iter().map(Self::into_attributes))?;
+        Ok(rules.into_iter().flatten().collect())
+    }
+
+    /// Every `#[serde(...)]` attribute among `attributes`,
+    /// or `None` if there are none.
+    fn from_attributes(attributes: &[Attribute]) -> Option<Vec<Self>> {
+        let lists: Vec<_> = attributes.iter().filter_map(Self::from_attribute).collect();
+        if lists.is_empty() {
+            None
+        } else {
+            Some(lists)
+        }
+    }
+
+    fn from_attribute(attr: &Attribute) -> Option<Self> {
         match attr.style {
             AttrStyle::Outer => {
                 if !attr.path().is_ident("serde") {
@@ -136,7 +623,7 @@ impl SerdeAttributeList {
 
                 match &attr.meta {
                     Meta::Path(_) => None,
-                    Meta::List(list) => Some(Ok(Self(list.clone()))),
+                    Meta::List(list) => Some(Self(list.clone())),
                     Meta::NameValue(_) => None,
                 }
             }
@@ -145,113 +632,492 @@ impl SerdeAttributeList {
     }
 }
 
-impl ast::SerdeContainerAttributes {
-    fn from_list(list: SerdeAttributeList) -> Result<Self, Error> {
-        let SerdeAttributeList(meta) = list;
+struct CamoAttributeList(MetaList);
 
-        let mut rules = Vec::new();
+impl CamoAttributeList {
+    /// Parses the keys of every `#[camo(...)]` attribute among `attributes`,
+    /// returning `false` if there are none.
+    fn parse_all<F>(attributes: &[Attribute], mut parse: F) -> Result<bool, Error>
+    where
+        F: FnMut(ParseNestedMeta) -> syn::Result<()>,
+    {
+        let lists = collect_all(attributes.iter().filter_map(Self::from_attribute))?;
+        if lists.is_empty() {
+            return Ok(false);
+        }
+        collect_all(lists.into_iter().map(|Self(list)| {
+            list.parse_nested_meta(&mut parse).map_err(|error| Error {
+                span: error.span(),
+                kind: ErrorKind::Syn(error),
+            })
+        }))?;
+        Ok(true)
+    }
 
-        let result = meta.parse_nested_meta(|meta| {
-            let lookahead = meta.input.lookahead1();
-            if !lookahead.peek(Token![=]) {
-                return Ok(());
+    fn from_attribute(attr: &Attribute) -> Option<Result<Self, Error>> {
+        match attr.style {
+            AttrStyle::Outer => {
+                if !attr.path().is_ident("camo") {
+                    return None;
+                }
+
+                match attr.meta.require_list() {
+                    Ok(list) => Some(Ok(Self(list.clone()))),
+                    Err(error) => Some(Err(Error {
+                        kind: ErrorKind::Syn(error),
+                        span: attr.span(),
+                    })),
+                }
             }
+            AttrStyle::Inner(_) => None,
+        }
+    }
+}
 
-            let value = meta.value()?;
-            let lit: LitStr = value.parse()?;
+/// The error for a key in `#[camo(...)]` that is not supported where it is used,
+/// which points at the key.
+fn unknown_camo_key(meta: &ParseNestedMeta, position: &str, expected: &str) -> syn::Error {
+    let key = meta
+        .path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::");
+    meta.error(format!(
+        "{} `{}` on {}, expected one of {}",
+        ErrorKind::UnknownAttribute.message(),
+        key,
+        position,
+        expected
+    ))
+}
 
-            match SerdeAttribute::from_pair(&meta.path, lit) {
-                Ok(Some(attr)) => {
-                    rules.push(attr);
-                    Ok(())
+impl ast::CamoContainerAttributes {
+    fn from_attributes(attributes: &[Attribute], data: &Data) -> Result<Option<Self>, Error> {
+        let mut camo = Self::default();
+        let found = CamoAttributeList::parse_all(attributes, |meta| {
+            if meta.path.is_ident("repr") {
+                check_repr(data, meta.path.span())
+                    .map_err(|error| syn::Error::new(error.span, error.kind.message()))?;
+                camo.repr = true;
+            } else if meta.path.is_ident("rename") {
+                camo.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("export") {
+                camo.export = Some(meta.value()?.parse::<LitBool>()?.value);
+            } else if meta.path.is_ident("strict") {
+                camo.strict = true;
+            } else {
+                return Err(unknown_camo_key(
+                    &meta,
+                    "a type",
+                    "`repr`, `rename`, `export` or `strict`",
+                ));
+            }
+            Ok(())
+        })?;
+        Ok(if found { Some(camo) } else { None })
+    }
+}
+
+impl ast::CamoFieldAttributes {
+    /// The attributes of a field, where unnamed fields (of tuple structs
+    /// and variants) cannot be renamed.
+    fn from_attributes(attributes: &[Attribute], named: bool) -> Result<Option<Self>, Error> {
+        let mut camo = Self::default();
+        let found = CamoAttributeList::parse_all(attributes, |meta| {
+            if named && meta.path.is_ident("rename") {
+                camo.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("skip") {
+                camo.skip = true;
+            } else if meta.path.is_ident("foreign") {
+                if camo.as_type.is_some() {
+                    return Err(meta.error("`camo`: `foreign` cannot be combined with `as`"));
+                }
+                camo.foreign = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("as") {
+                if camo.foreign.is_some() {
+                    return Err(meta.error("`camo`: `as` cannot be combined with `foreign`"));
                 }
-                Ok(None) => Ok(()),
-                Err(error) => Err(syn::Error::new(error.span, error.kind.message())),
+                camo.as_type = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+            } else if meta.path.is_ident("typescript") {
+                camo.typescript = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if named {
+                return Err(unknown_camo_key(
+                    &meta,
+                    "a field",
+                    "`rename`, `skip`, `foreign`, `as` or `typescript`",
+                ));
+            } else {
+                return Err(unknown_camo_key(
+                    &meta,
+                    "an unnamed field",
+                    "`skip`, `foreign`, `as` or `typescript`",
+                ));
             }
-        });
+            Ok(())
+        })?;
+        Ok(if found { Some(camo) } else { None })
+    }
+}
 
-        if let Err(error) = result {
-            return Err(Error {
-                kind: ErrorKind::Syn(error),
-                span: meta.span(),
-            });
+impl ast::CamoFieldAttributes {
+    /// The type that describes a field of the type `ty`.
+    ///
+    /// The overrides replace the Rust type of the field, which is then
+    /// not described at all, so that types that `camo` does not support
+    /// can still be used. The same goes for fields that are left out,
+    /// if their type is not supported.
+    fn field_type(&self, ty: syn::Type) -> Result<ast::Type, Error> {
+        if let Some(foreign) = &self.foreign {
+            Ok(ast::Type::Foreign(
+                foreign.split("::").map(str::to_string).collect(),
+            ))
+        } else if let Some(ty) = &self.as_type {
+            ast::Type::from_ty(ty.clone())
+        } else if self.typescript.is_some() {
+            Ok(ast::Type::Opaque)
+        } else if self.skip {
+            // Left out of the definitions, so the type only matters if it is supported
+            Ok(ast::Type::from_ty(ty).unwrap_or_else(|_| ast::Type::Opaque))
+        } else {
+            ast::Type::from_ty(ty)
         }
+    }
+}
+
+impl ast::CamoVariantAttributes {
+    fn from_attributes(attributes: &[Attribute]) -> Result<Option<Self>, Error> {
+        let mut camo = Self::default();
+        let found = CamoAttributeList::parse_all(attributes, |meta| {
+            if meta.path.is_ident("rename") {
+                camo.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("skip") {
+                camo.skip = true;
+            } else {
+                return Err(unknown_camo_key(&meta, "a variant", "`rename` or `skip`"));
+            }
+            Ok(())
+        })?;
+        Ok(if found { Some(camo) } else { None })
+    }
+}
+
+impl ast::SerdeContainerAttributes {
+    fn from_lists(lists: Vec<SerdeAttributeList>) -> Result<Self, Error> {
+        let rules = SerdeAttributeList::into_all_attributes(lists)?;
 
         let rename_all = rules.iter().find_map(|attr| match attr {
-            SerdeAttribute::Rename(_) => None,
             SerdeAttribute::RenameAll(r) => Some(*r),
-            SerdeAttribute::Tag(_) => None,
-            SerdeAttribute::Content(_) => None,
+            _ => None,
+        });
+
+        let rename_all_serialize = rules.iter().find_map(|attr| match attr {
+            SerdeAttribute::RenameAllSerialize(r) => Some(*r),
+            _ => None,
+        });
+
+        let rename_all_deserialize = rules.iter().find_map(|attr| match attr {
+            SerdeAttribute::RenameAllDeserialize(r) => Some(*r),
+            _ => None,
         });
 
         let rename = rules.iter().find_map(|attr| match attr {
-            SerdeAttribute::Rename(r) => Some(*r),
-            SerdeAttribute::RenameAll(_) => None,
-            SerdeAttribute::Tag(_) => None,
-            SerdeAttribute::Content(_) => None,
+            SerdeAttribute::Rename(s) => Some(s.clone()),
+            _ => None,
+        });
+
+        let rename_serialize = rules.iter().find_map(|attr| match attr {
+            SerdeAttribute::RenameSerialize(s) => Some(s.clone()),
+            _ => None,
+        });
+
+        let rename_deserialize = rules.iter().find_map(|attr| match attr {
+            SerdeAttribute::RenameDeserialize(s) => Some(s.clone()),
+            _ => None,
         });
 
         let tag = rules.iter().find_map(|attr| match attr {
-            SerdeAttribute::Rename(_) => None,
-            SerdeAttribute::RenameAll(_) => None,
             SerdeAttribute::Tag(s) => Some(s.clone()),
-            SerdeAttribute::Content(_) => None,
+            _ => None,
         });
 
         let content = rules.iter().find_map(|attr| match attr {
-            SerdeAttribute::Rename(_) => None,
-            SerdeAttribute::RenameAll(_) => None,
-            SerdeAttribute::Tag(_) => None,
             SerdeAttribute::Content(s) => Some(s.clone()),
+            _ => None,
+        });
+
+        let from = rules.iter().find_map(|attr| match attr {
+            SerdeAttribute::From(ty) => Some(ty.clone()),
+            _ => None,
+        });
+
+        let try_from = rules.iter().find_map(|attr| match attr {
+            SerdeAttribute::TryFrom(ty) => Some(ty.clone()),
+            _ => None,
+        });
+
+        let into = rules.iter().find_map(|attr| match attr {
+            SerdeAttribute::Into(ty) => Some(ty.clone()),
+            _ => None,
         });
 
         Ok(Self {
             rename,
+            rename_serialize,
+            rename_deserialize,
             rename_all,
+            rename_all_serialize,
+            rename_all_deserialize,
             tag,
             content,
+            untagged: rules
+                .iter()
+                .any(|attr| matches!(attr, SerdeAttribute::Untagged)),
+            transparent: rules
+                .iter()
+                .any(|attr| matches!(attr, SerdeAttribute::Transparent)),
+            from,
+            try_from,
+            into,
+            default: rules
+                .iter()
+                .any(|attr| matches!(attr, SerdeAttribute::Default)),
         })
     }
 }
 
+/// The `serde` attributes that are modeled on any type.
+const CONTAINER_KEYS: &[&str] = &[
+    "rename",
+    "rename_all",
+    "transparent",
+    "from",
+    "try_from",
+    "into",
+];
+/// The `serde` attributes that are modeled on structs, in addition to [`CONTAINER_KEYS`].
+const STRUCT_KEYS: &[&str] = &["default"];
+/// The `serde` attributes that are modeled on enums, in addition to [`CONTAINER_KEYS`].
+const ENUM_KEYS: &[&str] = &["tag", "content", "untagged"];
+/// The `serde` attributes that are modeled on named fields.
+const FIELD_KEYS: &[&str] = &[
+    "rename",
+    "alias",
+    "skip",
+    "skip_serializing",
+    "skip_deserializing",
+    "default",
+    "skip_serializing_if",
+    "flatten",
+];
+/// The `serde` attributes that are modeled on variants.
+const UNNAMED_FIELD_KEYS: &[&str] = &["skip", "skip_serializing", "skip_deserializing"];
+
+const VARIANT_KEYS: &[&str] = &[
+    "rename",
+    "alias",
+    "rename_all",
+    "skip",
+    "skip_serializing",
+    "skip_deserializing",
+    "untagged",
+];
+
+/// Reports every `serde` attribute that `camo` does not model
+/// (and would otherwise ignore), for `#[camo(strict)]`.
+fn check_strict(input: &DeriveInput) -> Result<(), Error> {
+    let mut errors = Vec::new();
+    let (position, keys) = match &input.data {
+        Data::Struct(_) => ("a struct", [CONTAINER_KEYS, STRUCT_KEYS].concat()),
+        Data::Enum(_) => ("an enum", [CONTAINER_KEYS, ENUM_KEYS].concat()),
+        // Reported when the item itself is parsed
+        Data::Union(_) => return Ok(()),
+    };
+    check_serde_keys(&input.attrs, position, &keys, &mut errors);
+    let fields: Vec<&Field> = match &input.data {
+        Data::Struct(data) => data.fields.iter().collect(),
+        Data::Enum(data) => {
+            for variant in &data.variants {
+                check_serde_keys(&variant.attrs, "a variant", VARIANT_KEYS, &mut errors);
+            }
+            data.variants
+                .iter()
+                .flat_map(|variant| variant.fields.iter())
+                .collect()
+        }
+        Data::Union(_) => Vec::new(),
+    };
+    for field in fields {
+        match field.ident {
+            Some(_) => check_serde_keys(&field.attrs, "a named field", FIELD_KEYS, &mut errors),
+            None => check_serde_keys(
+                &field.attrs,
+                "an unnamed field",
+                UNNAMED_FIELD_KEYS,
+                &mut errors,
+            ),
+        }
+    }
+    match errors.into_iter().reduce(|mut error, next| {
+        error.combine(next);
+        error
+    }) {
+        Some(error) => Err(Error {
+            span: error.span(),
+            kind: ErrorKind::Syn(error),
+        }),
+        None => Ok(()),
+    }
+}
+
+fn check_serde_keys(
+    attributes: &[Attribute],
+    position: &str,
+    keys: &[&str],
+    errors: &mut Vec<syn::Error>,
+) {
+    for SerdeAttributeList(list) in
+        SerdeAttributeList::from_attributes(attributes).unwrap_or_default()
+    {
+        let result = list.parse_nested_meta(|meta| {
+            if !keys.iter().any(|key| meta.path.is_ident(key)) {
+                let key = meta
+                    .path
+                    .get_ident()
+                    .map(ToString::to_string)
+                    .unwrap_or_default();
+                errors.push(meta.error(format!(
+                    "`camo(strict)`: `#[serde({})]` on {} is not modeled by `camo`, \
+                    so the generated definitions may not match what `serde` does",
+                    key, position
+                )));
+            }
+            // Skip the value, if any
+            if meta.input.peek(Token![=]) {
+                meta.value()?.parse::<Expr>()?;
+            } else if meta.input.peek(token::Paren) {
+                let content;
+                parenthesized!(content in meta.input);
+                content.parse::<TokenStream>()?;
+            }
+            Ok(())
+        });
+        // Malformed attributes are reported when they are parsed, so the
+        // error is not reported twice
+        result.ok();
+    }
+}
+
 enum SerdeAttribute {
-    Rename(ast::RenameRule),
+    Rename(String),
+    RenameSerialize(String),
+    RenameDeserialize(String),
+    Alias(String),
     RenameAll(ast::RenameRule),
+    RenameAllSerialize(ast::RenameRule),
+    RenameAllDeserialize(ast::RenameRule),
     Tag(String),
     Content(String),
+    Skip,
+    SkipSerializing,
+    SkipDeserializing,
+    Default,
+    SkipSerializingIf(String),
+    Flatten,
+    Untagged,
+    Transparent,
+    From(ast::Type),
+    TryFrom(ast::Type),
+    Into(ast::Type),
 }
 
 impl SerdeAttribute {
     fn from_pair(path: &Path, literal: LitStr) -> Result<Option<Self>, Error> {
         if path.is_ident("rename_all") {
-            if let Some(rule) = ast::RenameRule::from_string(literal.value()) {
-                Ok(Some(Self::RenameAll(rule)))
-            } else {
-                Err(Error {
-                    kind: ErrorKind::InvalidRenameRule,
-                    span: literal.span(),
-                })
-            }
+            Ok(Some(Self::RenameAll(ast::RenameRule::from_literal(
+                &literal,
+            )?)))
         } else if path.is_ident("rename") {
-            if let Some(rule) = ast::RenameRule::from_string(literal.value()) {
-                Ok(Some(Self::Rename(rule)))
-            } else {
-                Err(Error {
-                    kind: ErrorKind::InvalidRenameRule,
-                    span: literal.span(),
-                })
-            }
+            Ok(Some(Self::Rename(literal.value())))
+        } else if path.is_ident("alias") {
+            Ok(Some(Self::Alias(literal.value())))
         } else if path.is_ident("tag") {
             Ok(Some(Self::Tag(literal.value())))
         } else if path.is_ident("content") {
             Ok(Some(Self::Content(literal.value())))
+        } else if path.is_ident("default") {
+            Ok(Some(Self::Default))
+        } else if path.is_ident("skip_serializing_if") {
+            Ok(Some(Self::SkipSerializingIf(literal.value())))
+        } else if path.is_ident("from") {
+            Ok(Some(Self::From(ast::Type::from_literal(&literal)?)))
+        } else if path.is_ident("try_from") {
+            Ok(Some(Self::TryFrom(ast::Type::from_literal(&literal)?)))
+        } else if path.is_ident("into") {
+            Ok(Some(Self::Into(ast::Type::from_literal(&literal)?)))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn from_directional_pair(
+        outer: &Path,
+        path: &Path,
+        literal: LitStr,
+    ) -> Result<Option<Self>, Error> {
+        let serialize = path.is_ident("serialize");
+        let deserialize = path.is_ident("deserialize");
+        if outer.is_ident("rename_all") {
+            if serialize {
+                Ok(Some(Self::RenameAllSerialize(
+                    ast::RenameRule::from_literal(&literal)?,
+                )))
+            } else if deserialize {
+                Ok(Some(Self::RenameAllDeserialize(
+                    ast::RenameRule::from_literal(&literal)?,
+                )))
+            } else {
+                Ok(None)
+            }
+        } else if serialize {
+            Ok(Some(Self::RenameSerialize(literal.value())))
+        } else if deserialize {
+            Ok(Some(Self::RenameDeserialize(literal.value())))
         } else {
             Ok(None)
         }
     }
+
+    fn from_path(path: &Path) -> Option<Self> {
+        if path.is_ident("skip") {
+            Some(Self::Skip)
+        } else if path.is_ident("skip_serializing") {
+            Some(Self::SkipSerializing)
+        } else if path.is_ident("skip_deserializing") {
+            Some(Self::SkipDeserializing)
+        } else if path.is_ident("default") {
+            Some(Self::Default)
+        } else if path.is_ident("flatten") {
+            Some(Self::Flatten)
+        } else if path.is_ident("untagged") {
+            Some(Self::Untagged)
+        } else if path.is_ident("transparent") {
+            Some(Self::Transparent)
+        } else {
+            None
+        }
+    }
 }
 
 impl ast::RenameRule {
+    fn from_literal(literal: &LitStr) -> Result<Self, Error> {
+        Self::from_string(literal.value()).ok_or_else(|| Error {
+            kind: ErrorKind::InvalidRenameRule,
+            span: literal.span(),
+        })
+    }
+
     fn from_string(s: String) -> Option<Self> {
         match s.as_str() {
             "lowercase" => Some(Self::Lower),
@@ -268,10 +1134,15 @@ impl ast::RenameRule {
 }
 
 impl ast::Item {
-    fn from_input(input: DeriveInput) -> Result<Self, Error> {
+    /// The item, where `export` is the value of `#[camo(export = ...)]`, if any.
+    fn from_input(input: DeriveInput, export: Option<bool>) -> Result<Self, Error> {
         let name = input.ident;
         let generics = input.generics;
-        let visibility = input.vis;
+        let visibility = match input.vis {
+            // The visibility is not needed when exporting is decided explicitly
+            Visibility::Restricted(_) if export.is_some() => Visibility::Inherited,
+            visibility => visibility,
+        };
         match input.data {
             Data::Struct(data) => Ok(ast::Item::Struct(ast::Struct::from_content(
                 visibility, name, generics, data,
@@ -282,7 +1153,8 @@ impl ast::Item {
             Data::Union(data) => Err(Error {
                 kind: ErrorKind::Union,
                 span: data.union_token.span(),
-            }),
+            }
+            .within(&format!("union `{}`", name), None)),
         }
     }
 }
@@ -307,80 +1179,257 @@ impl ast::Struct {
         generics: Generics,
         data: DataStruct,
     ) -> Result<Self, Error> {
+        let construct = format!("struct `{}`", name);
+        let visibility = ast::Visibility::from_visibility(visibility)
+            .map_err(|error| error.within(&construct, Some(VISIBILITY_HINT)));
+        let parameters = ast::GenericParameter::from_generics(generics, &construct);
+        let content = ast::StructContent::from_fields(data.fields, &construct);
+        let ((visibility, parameters), content) = join(join(visibility, parameters), content)?;
         Ok(ast::Struct {
-            visibility: ast::Visibility::from_visibility(visibility)?,
+            visibility,
             name: name.to_string(),
-            parameters: generics
-                .params
-                .into_iter()
-                .map(ast::GenericParameter::from_param)
-                .collect::<Result<_, _>>()?,
-            content: ast::StructContent::from_fields(data.fields)?,
+            parameters,
+            content,
         })
     }
 }
 
 impl ast::GenericParameter {
-    fn from_param(parameter: GenericParam) -> Result<Self, Error> {
-        match parameter {
-            GenericParam::Type(ty) => {
-                if !ty.bounds.is_empty() {
-                    return Err(Error {
-                        kind: ErrorKind::GenericBounds,
-                        span: ty.bounds.span(),
-                    });
+    /// The parameters of `owner` (as in "struct `Foo`").
+    fn from_generics(generics: Generics, owner: &str) -> Result<Vec<Self>, Error> {
+        let mut parameters = collect_all(generics.params.into_iter().map(|parameter| {
+            let name = match &parameter {
+                GenericParam::Type(ty) => ty.ident.to_string(),
+                GenericParam::Lifetime(lt) => lt.lifetime.to_string(),
+                GenericParam::Const(c) => c.ident.to_string(),
+            };
+            ast::GenericParameter::from_param(parameter).map_err(|error| {
+                error.within(&format!("generic parameter `{}` of {}", name, owner), None)
+            })
+        }))?;
+
+        // Bounds on a parameter itself (e.g. `where T: Clone`) are added to
+        // its other bounds, while any other predicates are ignored
+        if let Some(where_clause) = generics.where_clause {
+            for predicate in where_clause.predicates {
+                let predicate = match predicate {
+                    WherePredicate::Type(predicate) => predicate,
+                    _ => continue,
+                };
+                let ident = match &predicate.bounded_ty {
+                    syn::Type::Path(ty) if ty.qself.is_none() => ty.path.get_ident(),
+                    _ => None,
+                };
+                let parameter = parameters.iter_mut().find_map(|parameter| match parameter {
+                    ast::GenericParameter::Type(parameter)
+                        if ident.is_some_and(|ident| ident == &parameter.name) =>
+                    {
+                        Some(parameter)
+                    }
+                    _ => None,
+                });
+                if let Some(parameter) = parameter {
+                    parameter.bounds.extend(bounds(predicate.bounds));
                 }
-                Ok(Self::Type(ty.ident.to_string()))
             }
+        }
+
+        Ok(parameters)
+    }
+
+    fn from_param(parameter: GenericParam) -> Result<Self, Error> {
+        match parameter {
+            GenericParam::Type(ty) => Ok(Self::Type(ast::TypeParameter {
+                name: ty.ident.to_string(),
+                bounds: bounds(ty.bounds),
+                default: ty.default.map(ast::Type::from_ty).transpose()?,
+            })),
             GenericParam::Lifetime(lt) => Ok(Self::Lifetime(lt.lifetime.ident.to_string())),
-            GenericParam::Const(c) => Err(Error {
-                kind: ErrorKind::ConstGenerics,
-                span: c.span(),
-            }),
+            GenericParam::Const(c) => Ok(Self::Const(ast::ConstParameter {
+                name: c.ident.to_string(),
+                ty: ast::Type::from_ty(c.ty)?,
+            })),
         }
     }
 }
 
+/// The trait bounds among the given bounds, as paths to the traits.
+///
+/// Lifetime bounds (e.g. `T: 'a`) and relaxed bounds (e.g. `T: ?Sized`)
+/// are left out, as are bounds that cannot be described as a path,
+/// like `T: Iterator<Item = u32>` or `F: Fn(u32)`.
+fn bounds<P>(bounds: Punctuated<TypeParamBound, P>) -> Vec<ast::TypePath> {
+    bounds
+        .into_iter()
+        .filter_map(|bound| match bound {
+            TypeParamBound::Trait(bound) if matches!(bound.modifier, TraitBoundModifier::None) => {
+                ast::TypePath::from_type_path(TypePath {
+                    qself: None,
+                    path: bound.path,
+                })
+                .ok()
+            }
+            _ => None,
+        })
+        .collect()
+}
+
 impl ast::StructContent {
-    fn from_fields(fields: Fields) -> Result<Self, Error> {
+    /// The fields of `owner` (as in "struct `Foo`").
+    fn from_fields(fields: Fields, owner: &str) -> Result<Self, Error> {
         match fields {
-            Fields::Named(fields) => {
-                let fields: Result<_, _> = fields
+            Fields::Named(fields) => Ok(ast::StructContent::NamedFields(collect_all(
+                fields
                     .named
                     .into_iter()
-                    .map(|field| {
-                        Ok(ast::NamedField {
-                            name: field.ident.as_ref().expect("named field").to_string(),
-                            ty: ast::Type::from_ty(field.ty)?,
-                        })
-                    })
-                    .collect();
-                Ok(ast::StructContent::NamedFields(fields?))
-            }
-            Fields::Unnamed(fields) => {
-                if fields.unnamed.len() > 1 {
-                    return Err(Error {
-                        kind: ErrorKind::StructMultipleUnnamedFields,
-                        span: fields.span(),
+                    .map(|field| ast::NamedField::from_field(field, owner)),
+            )?)),
+            Fields::Unnamed(fields) => Ok(ast::StructContent::UnnamedFields(
+                ast::UnnamedField::from_fields(fields, owner)?,
+            )),
+            Fields::Unit => Ok(ast::StructContent::Unit),
+        }
+    }
+}
+
+impl ast::NamedField {
+    /// A field of `owner` (as in "struct `Foo`").
+    fn from_field(field: Field, owner: &str) -> Result<Self, Error> {
+        let name = field.ident.as_ref().expect("named field").to_string();
+
+        let serde = SerdeAttributeList::from_attributes(&field.attrs)
+            .map(ast::SerdeFieldAttributes::from_lists)
+            .transpose();
+
+        let camo = ast::CamoFieldAttributes::from_attributes(&field.attrs, true);
+        let docs = ast::Docs::from_attributes(&field.attrs);
+
+        let ty = match &camo {
+            Ok(Some(camo)) => camo.field_type(field.ty),
+            Ok(None) => ast::Type::from_ty(field.ty),
+            // Not checked, as the attributes may have been meant to override it
+            Err(_) => Ok(ast::Type::Opaque),
+        }
+        .map_err(|error| {
+            let construct = format!("field `{}` of {}", name, owner);
+            error.within(&construct, Some(FIELD_TYPE_HINT))
+        });
+
+        let (((serde, camo), docs), ty) = join(join(join(serde, camo), docs), ty)?;
+
+        Ok(ast::NamedField {
+            serde,
+            camo,
+            docs,
+            name,
+            ty,
+        })
+    }
+}
+
+impl ast::UnnamedField {
+    /// The fields of `owner` (as in "struct `Foo`").
+    fn from_fields(fields: FieldsUnnamed, owner: &str) -> Result<Vec<Self>, Error> {
+        collect_all(
+            fields
+                .unnamed
+                .into_iter()
+                .enumerate()
+                .map(|(index, field)| {
+                    // Unnamed fields have no names and cannot be missing,
+                    // so only the `skip` keys concern them
+                    let serde = SerdeAttributeList::from_attributes(&field.attrs)
+                        .map(ast::SerdeFieldAttributes::from_lists)
+                        .transpose()
+                        .map(|serde| {
+                            serde.map(|serde| ast::SerdeFieldAttributes {
+                                skip: serde.skip,
+                                skip_serializing: serde.skip_serializing,
+                                skip_deserializing: serde.skip_deserializing,
+                                ..ast::SerdeFieldAttributes::default()
+                            })
+                        });
+
+                    let camo = ast::CamoFieldAttributes::from_attributes(&field.attrs, false);
+
+                    let ty = match &camo {
+                        Ok(Some(camo)) => camo.field_type(field.ty),
+                        Ok(None) => ast::Type::from_ty(field.ty),
+                        // Not checked, as the attributes may have been meant to override it
+                        Err(_) => Ok(ast::Type::Opaque),
+                    }
+                    .map_err(|error| {
+                        let construct = format!("field `{}` of {}", index, owner);
+                        error.within(&construct, Some(FIELD_TYPE_HINT))
                     });
-                }
-                let span = fields.span();
-                if let Some(field) = fields.unnamed.into_iter().next() {
-                    Ok(ast::StructContent::UnnamedField(ast::UnnamedField {
-                        ty: ast::Type::from_ty(field.ty)?,
-                    }))
-                } else {
-                    Err(Error {
-                        kind: ErrorKind::UnitStruct,
-                        span,
+
+                    let ((serde, camo), ty) = join(join(serde, camo), ty)?;
+
+                    Ok(ast::UnnamedField {
+                        serde,
+                        camo,
+                        ty,
+                        collapse_unit_struct: false,
                     })
-                }
-            }
-            Fields::Unit => Err(Error {
-                kind: ErrorKind::UnitStruct,
-                span: fields.span(),
-            }),
-        }
+                }),
+        )
+    }
+}
+
+impl ast::SerdeFieldAttributes {
+    fn from_lists(lists: Vec<SerdeAttributeList>) -> Result<Self, Error> {
+        let rules = SerdeAttributeList::into_all_attributes(lists)?;
+
+        let rename = rules.iter().find_map(|attr| match attr {
+            SerdeAttribute::Rename(s) => Some(s.clone()),
+            _ => None,
+        });
+
+        let rename_serialize = rules.iter().find_map(|attr| match attr {
+            SerdeAttribute::RenameSerialize(s) => Some(s.clone()),
+            _ => None,
+        });
+
+        let rename_deserialize = rules.iter().find_map(|attr| match attr {
+            SerdeAttribute::RenameDeserialize(s) => Some(s.clone()),
+            _ => None,
+        });
+
+        let aliases = rules
+            .iter()
+            .filter_map(|attr| match attr {
+                SerdeAttribute::Alias(s) => Some(s.clone()),
+                _ => None,
+            })
+            .collect();
+
+        let skip_serializing_if = rules.iter().find_map(|attr| match attr {
+            SerdeAttribute::SkipSerializingIf(s) => Some(s.clone()),
+            _ => None,
+        });
+
+        Ok(Self {
+            rename,
+            rename_serialize,
+            rename_deserialize,
+            aliases,
+            skip: rules
+                .iter()
+                .any(|attr| matches!(attr, SerdeAttribute::Skip)),
+            skip_serializing: rules
+                .iter()
+                .any(|attr| matches!(attr, SerdeAttribute::SkipSerializing)),
+            skip_deserializing: rules
+                .iter()
+                .any(|attr| matches!(attr, SerdeAttribute::SkipDeserializing)),
+            default: rules
+                .iter()
+                .any(|attr| matches!(attr, SerdeAttribute::Default)),
+            skip_serializing_if,
+            flatten: rules
+                .iter()
+                .any(|attr| matches!(attr, SerdeAttribute::Flatten)),
+        })
     }
 }
 
@@ -391,141 +1440,201 @@ impl ast::Enum {
         generics: Generics,
         data: DataEnum,
     ) -> Result<Self, Error> {
+        let construct = format!("enum `{}`", name);
+        let visibility = ast::Visibility::from_visibility(visibility)
+            .map_err(|error| error.within(&construct, Some(VISIBILITY_HINT)));
+        let parameters = ast::GenericParameter::from_generics(generics, &construct);
+        let variants = collect_all(
+            data.variants
+                .into_iter()
+                .map(|variant| ast::Variant::from_variant(variant, &name)),
+        );
+        let ((visibility, parameters), variants) = join(join(visibility, parameters), variants)?;
         Ok(ast::Enum {
-            visibility: ast::Visibility::from_visibility(visibility)?,
+            visibility,
             name: name.to_string(),
-            parameters: generics
-                .params
-                .into_iter()
-                .map(ast::GenericParameter::from_param)
-                .collect::<Result<_, _>>()?,
-            variants: data
-                .variants
-                .into_iter()
-                .map(ast::Variant::from_variant)
-                .collect::<Result<_, _>>()?,
+            parameters,
+            variants,
         })
     }
 }
 
 impl ast::Variant {
-    fn from_variant(variant: Variant) -> Result<Self, Error> {
-        if let Some((_, expr)) = variant.discriminant {
-            return Err(Error {
-                kind: ErrorKind::ExplicicitDiscriminant,
-                span: expr.span(),
-            });
-        }
+    /// A variant of the enum `owner`.
+    fn from_variant(variant: Variant, owner: &Ident) -> Result<Self, Error> {
+        let construct = format!("variant `{}::{}`", owner, variant.ident);
 
-        let serde = variant
-            .attrs
-            .iter()
-            .find_map(SerdeAttributeList::from_attribute)
-            .transpose()?
-            .map(ast::SerdeVariantAttributes::from_list)
-            .transpose()?;
+        let discriminant = variant
+            .discriminant
+            .map(|(_, expr)| discriminant_from_expr(&expr))
+            .transpose()
+            .map_err(|error| error.within(&format!("the discriminant of {}", construct), None));
+
+        let serde = SerdeAttributeList::from_attributes(&variant.attrs)
+            .map(ast::SerdeVariantAttributes::from_lists)
+            .transpose();
+
+        let camo = ast::CamoVariantAttributes::from_attributes(&variant.attrs);
+        let docs = ast::Docs::from_attributes(&variant.attrs);
+        let content = ast::VariantContent::from_fields(variant.fields, &construct);
 
-        let content = ast::VariantContent::from_fields(variant.fields)?;
+        let ((((discriminant, serde), camo), docs), content) =
+            join(join(join(join(discriminant, serde), camo), docs), content)?;
 
         Ok(ast::Variant {
             serde,
+            camo,
+            docs,
             name: variant.ident.to_string(),
+            discriminant,
             content,
         })
     }
 }
 
-impl ast::SerdeVariantAttributes {
-    fn from_list(list: SerdeAttributeList) -> Result<Self, Error> {
-        let SerdeAttributeList(meta) = list;
-
-        let mut rules = Vec::new();
-
-        let result = meta.parse_nested_meta(|meta| {
-            let lookahead = meta.input.lookahead1();
-            if !lookahead.peek(Token![=]) {
-                return Ok(());
+fn discriminant_from_expr(expr: &Expr) -> Result<i128, Error> {
+    let (negative, literal) = match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(literal),
+            ..
+        }) => (false, literal),
+        Expr::Unary(ExprUnary {
+            op: UnOp::Neg(_),
+            expr,
+            ..
+        }) => match &**expr {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(literal),
+                ..
+            }) => (true, literal),
+            _ => {
+                return Err(Error {
+                    kind: ErrorKind::InvalidDiscriminant,
+                    span: expr.span(),
+                })
             }
+        },
+        _ => {
+            return Err(Error {
+                kind: ErrorKind::InvalidDiscriminant,
+                span: expr.span(),
+            })
+        }
+    };
 
-            let value = meta.value()?;
-            let lit: LitStr = value.parse()?;
+    let value: i128 = literal.base10_parse().map_err(|error| Error {
+        kind: ErrorKind::Syn(error),
+        span: literal.span(),
+    })?;
 
-            match SerdeAttribute::from_pair(&meta.path, lit) {
-                Ok(Some(attr)) => {
-                    rules.push(attr);
-                    Ok(())
-                }
-                Ok(None) => Ok(()),
-                Err(error) => Err(syn::Error::new(error.span, error.kind.message())),
-            }
-        });
+    Ok(if negative { -value } else { value })
+}
 
-        if let Err(error) = result {
-            return Err(Error {
-                kind: ErrorKind::Syn(error),
-                span: meta.span(),
-            });
-        }
+impl ast::SerdeVariantAttributes {
+    fn from_lists(lists: Vec<SerdeAttributeList>) -> Result<Self, Error> {
+        let rules = SerdeAttributeList::into_all_attributes(lists)?;
 
         let rename_all = rules.iter().find_map(|attr| match attr {
-            SerdeAttribute::Rename(_) => None,
             SerdeAttribute::RenameAll(r) => Some(*r),
-            SerdeAttribute::Tag(_) => None,
-            SerdeAttribute::Content(_) => None,
+            _ => None,
+        });
+
+        let rename_all_serialize = rules.iter().find_map(|attr| match attr {
+            SerdeAttribute::RenameAllSerialize(r) => Some(*r),
+            _ => None,
+        });
+
+        let rename_all_deserialize = rules.iter().find_map(|attr| match attr {
+            SerdeAttribute::RenameAllDeserialize(r) => Some(*r),
+            _ => None,
         });
 
         let rename = rules.iter().find_map(|attr| match attr {
-            SerdeAttribute::Rename(r) => Some(*r),
-            SerdeAttribute::RenameAll(_) => None,
-            SerdeAttribute::Tag(_) => None,
-            SerdeAttribute::Content(_) => None,
+            SerdeAttribute::Rename(s) => Some(s.clone()),
+            _ => None,
+        });
+
+        let rename_serialize = rules.iter().find_map(|attr| match attr {
+            SerdeAttribute::RenameSerialize(s) => Some(s.clone()),
+            _ => None,
+        });
+
+        let rename_deserialize = rules.iter().find_map(|attr| match attr {
+            SerdeAttribute::RenameDeserialize(s) => Some(s.clone()),
+            _ => None,
         });
 
-        Ok(Self { rename, rename_all })
+        let aliases = rules
+            .iter()
+            .filter_map(|attr| match attr {
+                SerdeAttribute::Alias(s) => Some(s.clone()),
+                _ => None,
+            })
+            .collect();
+
+        Ok(Self {
+            rename,
+            rename_serialize,
+            rename_deserialize,
+            aliases,
+            rename_all,
+            rename_all_serialize,
+            rename_all_deserialize,
+            skip: rules
+                .iter()
+                .any(|attr| matches!(attr, SerdeAttribute::Skip)),
+            skip_serializing: rules
+                .iter()
+                .any(|attr| matches!(attr, SerdeAttribute::SkipSerializing)),
+            skip_deserializing: rules
+                .iter()
+                .any(|attr| matches!(attr, SerdeAttribute::SkipDeserializing)),
+            untagged: rules
+                .iter()
+                .any(|attr| matches!(attr, SerdeAttribute::Untagged)),
+        })
     }
 }
 
 impl ast::VariantContent {
-    fn from_fields(fields: Fields) -> Result<Self, Error> {
+    /// The fields of `owner` (as in "variant `Foo::Bar`").
+    fn from_fields(fields: Fields, owner: &str) -> Result<Self, Error> {
         match fields {
-            Fields::Named(fields) => {
-                let fields = fields
+            Fields::Named(fields) => Ok(ast::VariantContent::Named(collect_all(
+                fields
                     .named
                     .into_iter()
-                    .map(|field| {
-                        Ok(ast::NamedField {
-                            name: field.ident.as_ref().unwrap().to_string(),
-                            ty: ast::Type::from_ty(field.ty)?,
-                        })
-                    })
-                    .collect::<Result<_, _>>()?;
-                Ok(ast::VariantContent::Named(fields))
-            }
-            Fields::Unnamed(fields) => {
-                if fields.unnamed.len() > 1 {
-                    return Err(Error {
-                        kind: ErrorKind::EnumMultipleUnnamedFields,
-                        span: fields.span(),
-                    });
-                }
-                let field = fields.unnamed.into_iter().next().unwrap();
-                let ty = ast::Type::from_ty(field.ty)?;
-                Ok(ast::VariantContent::Unnamed(ty))
-            }
+                    .map(|field| ast::NamedField::from_field(field, owner)),
+            )?)),
+            Fields::Unnamed(fields) => Ok(ast::VariantContent::Unnamed(
+                ast::UnnamedField::from_fields(fields, owner)?,
+            )),
             Fields::Unit => Ok(ast::VariantContent::Unit),
         }
     }
 }
 
 impl ast::Type {
+    /// Parses a type written in a string literal, as in `#[serde(into = "String")]`.
+    fn from_literal(literal: &LitStr) -> Result<Self, Error> {
+        match literal.parse() {
+            Ok(ty) => Self::from_ty(ty),
+            Err(error) => Err(Error {
+                kind: ErrorKind::Syn(error),
+                span: literal.span(),
+            }),
+        }
+    }
+
     fn from_ty(ty: syn::Type) -> Result<Self, Error> {
         match ty {
             syn::Type::Slice(ty) => Ok(ast::Type::Slice(ast::SliceType::from(ast::Type::from_ty(
                 *ty.elem,
             )?))),
-            syn::Type::Array(ty) => Ok(ast::Type::Array(ast::ArrayType::from(ast::Type::from_ty(
-                *ty.elem,
-            )?))),
+            syn::Type::Array(ty) => Ok(ast::Type::Array(ast::ArrayType {
+                length: array_length(&ty.len),
+                ty: Box::new(ast::Type::from_ty(*ty.elem)?),
+            })),
             syn::Type::BareFn(ty) => Err(Error {
                 kind: ErrorKind::FunctionTypes,
                 span: ty.span(),
@@ -540,12 +1649,17 @@ impl ast::Type {
             syn::Type::Reference(ty) => Ok(ast::Type::Reference(
                 ast::ReferenceType::from_type_reference(ty)?,
             )),
+            syn::Type::Tuple(ty) => Ok(ast::Type::Tuple(ast::TupleType::from(
+                ty.elems
+                    .into_iter()
+                    .map(ast::Type::from_ty)
+                    .collect::<Result<Vec<_>, _>>()?,
+            ))),
             syn::Type::Infer(_)
             | syn::Type::Never(_)
             | syn::Type::ImplTrait(_)
             | syn::Type::Ptr(_)
             | syn::Type::TraitObject(_)
-            | syn::Type::Tuple(_)
             | syn::Type::Verbatim(_) => Err(Error {
                 kind: ErrorKind::MiscTypes,
                 span: ty.span(),
@@ -558,12 +1672,29 @@ impl ast::Type {
     }
 }
 
+/// The length of an array type, if it is an integer literal
+/// (rather than e.g. a constant, which cannot be evaluated here).
+fn array_length(expr: &Expr) -> Option<usize> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(literal),
+            ..
+        }) => literal.base10_parse().ok(),
+        Expr::Group(group) => array_length(&group.expr),
+        Expr::Paren(paren) => array_length(&paren.expr),
+        _ => None,
+    }
+}
+
 impl ast::ReferenceType {
+    /// A reference type, where an elided lifetime (only possible in types
+    /// written in string literals, as in `#[camo(as = "&str")]`) becomes `'_`.
     fn from_type_reference(ty: TypeReference) -> Result<Self, Error> {
+        let lifetime = ty
+            .lifetime
+            .map_or_else(|| String::from("_"), |lifetime| lifetime.ident.to_string());
         Ok(Self {
-            lifetime: ast::Lifetime {
-                name: ty.lifetime.expect("missing lifetime").ident.to_string(),
-            },
+            lifetime: ast::Lifetime { name: lifetime },
             ty: Box::new(ast::Type::from_ty(*ty.elem)?),
         })
     }
@@ -581,11 +1712,12 @@ impl ast::TypePath {
         let segments: Vec<_> = ty
             .path
             .segments
+            .clone()
             .into_iter()
             .map(ast::PathSegment::from_segment)
             .collect::<Result<_, _>>()?;
 
-        Ok(ast::TypePath { segments })
+        Ok(ast::TypePath { segments, ty })
     }
 }
 
@@ -600,7 +1732,12 @@ impl ast::PathSegment {
                     .into_iter()
                     .map(ast::GenericArgument::from_argument)
                     .collect::<Result<_, _>>()?,
-                PathArguments::Parenthesized(_) => todo!(),
+                PathArguments::Parenthesized(arguments) => {
+                    return Err(Error {
+                        kind: ErrorKind::MiscTypes,
+                        span: arguments.span(),
+                    })
+                }
             },
         })
     }
@@ -24,8 +24,41 @@ use syn::{parse_macro_input, DeriveInput};
 /// Derives an implementation of the `Camo` trait.
 ///
 /// The macro understands the `serde`-attributes `rename`, `rename_all`,
-/// `tag`, and `content`, both on the container type and on enum variants.
-#[proc_macro_derive(Camo, attributes(serde))]
+/// `tag`, `content`, and `untagged`, both on the container type and on
+/// enum variants. Containers may also use `transparent`, `from`,
+/// `try_from`, and `into`.
+/// On named fields, it understands `rename`, `alias`, `skip`, `skip_serializing`,
+/// `skip_deserializing`, `default`, `skip_serializing_if`, and `flatten`,
+/// and on unnamed fields `skip`, `skip_serializing`, and `skip_deserializing`.
+/// `rename` and `rename_all` may also be given per direction, as in
+/// `rename(serialize = "...", deserialize = "...")`.
+///
+/// Enums that are serialized as their integer discriminant (e.g. with
+/// `serde_repr`) can be marked with `#[camo(repr)]`.
+///
+/// The `#[camo(...)]` attribute changes only the generated definitions,
+/// not what `serde` does:
+///
+/// - On types, `rename = "..."` names the definition and `export = false`
+///   (or `true`) decides whether it is exported.
+/// - On named fields and variants, `rename = "..."` names them.
+/// - On fields (named or unnamed) and variants, `skip` leaves them out.
+/// - On fields, `foreign = "..."` describes the field as the named type,
+///   which is defined outside of the generated definitions.
+/// - On fields, `as = "..."` describes the field as the given Rust type,
+///   and `typescript = "..."` gives the TypeScript type of the field.
+///   Either way, the type of the field itself is not described, so these
+///   can be used for types that `camo` does not support, like trait objects.
+///   Fields that are left out with `skip` may have such types as well.
+/// - Types with a restricted visibility, like `pub(crate)`, need an explicit
+///   `export = ...`.
+///
+/// Any other `serde` attribute is ignored, so the generated definitions may
+/// not match what `serde` does. With `#[camo(strict)]` on the type (or the
+/// `strict` feature, for every type), these attributes are reported as errors.
+///
+/// Every error in a type is reported at once, rather than only the first one.
+#[proc_macro_derive(Camo, attributes(serde, camo))]
 pub fn derive_macro_impl(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let output = derive(input);
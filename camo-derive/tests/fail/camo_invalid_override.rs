@@ -0,0 +1,15 @@
+use camo_derive::Camo;
+
+#[derive(Camo)]
+struct Foo {
+    #[camo(as = "Vec<")]
+    foo: Box<dyn Fn()>,
+}
+
+#[derive(Camo)]
+struct Bar {
+    #[camo(foreign = "Bar", as = "u32")]
+    bar: Box<dyn Fn()>,
+}
+
+fn main() {}
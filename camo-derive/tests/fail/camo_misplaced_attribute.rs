@@ -1,8 +1,9 @@
 use camo_derive::Camo;
 
 #[derive(Camo)]
-struct Foo<const N: usize> {
-    foo: [i32; N],
+enum Foo {
+    #[camo(foreign = "Bar")]
+    Foo,
 }
 
 fn main() {}
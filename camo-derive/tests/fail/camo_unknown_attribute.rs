@@ -0,0 +1,9 @@
+use camo_derive::Camo;
+
+#[derive(Camo)]
+struct Foo {
+    #[camo(rename = "bar", foreing = "Bar")]
+    foo: u32,
+}
+
+fn main() {}
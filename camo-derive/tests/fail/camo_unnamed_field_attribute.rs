@@ -0,0 +1,11 @@
+use camo_derive::Camo;
+
+#[derive(Camo)]
+struct Foo(#[camo(rename = "bar")] u32);
+
+#[derive(Camo)]
+enum Bar {
+    Baz(#[camo(skip, foreing = "Baz")] u32),
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use camo_derive::Camo;
+
+#[derive(Camo)]
+#[serde(tag = "tag")]
+enum Foo {
+    Move(u32, bool),
+    Stay(u32),
+    #[serde(untagged)]
+    Other(u32, u32),
+}
+
+fn main() {}
@@ -0,0 +1,21 @@
+use camo_derive::Camo;
+
+#[derive(Camo)]
+pub(crate) struct Foo {
+    #[camo(unknown)]
+    a: u32,
+    b: fn(i32) -> i32,
+    c: Box<dyn Send>,
+    e: (u32, fn()),
+}
+
+#[derive(Camo)]
+#[repr(u8)]
+enum Bar {
+    #[serde(rename_all = "invalid")]
+    A(i32, fn()),
+    B { x: *const u8 },
+    C = 1 + 2,
+}
+
+fn main() {}
@@ -2,7 +2,7 @@ use camo_derive::Camo;
 
 #[derive(Camo)]
 enum Foo {
-    One = 1,
+    One = 1 << 2,
 }
 
 fn main() {}
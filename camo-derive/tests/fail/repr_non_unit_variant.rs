@@ -1,8 +1,10 @@
 use camo_derive::Camo;
 
 #[derive(Camo)]
+#[camo(repr)]
 enum Foo {
-    One(i32, i32),
+    One,
+    Two(i32),
 }
 
 fn main() {}
@@ -1,8 +1,7 @@
 use camo_derive::Camo;
 
 #[derive(Camo)]
-struct Foo<T: Clone> {
-    foo: T,
-}
+#[serde(into = "not a type")]
+struct Foo(u32);
 
 fn main() {}
@@ -0,0 +1,25 @@
+use camo_derive::Camo;
+
+#[derive(Camo)]
+#[camo(strict)]
+#[serde(deny_unknown_fields, bound = "T: Clone")]
+#[serde(rename_all_fields = "camelCase")]
+struct Foo<T> {
+    foo: T,
+}
+
+#[derive(Camo)]
+#[camo(strict)]
+#[serde(remote = "Other", tag = "type", untagged)]
+struct Bar {
+    bar: u32,
+}
+
+#[derive(Camo)]
+#[camo(strict)]
+#[serde(default, expecting = "a baz", crate = "my_serde")]
+enum Baz {
+    Baz,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use camo_derive::Camo;
+
+#[derive(Camo)]
+#[serde(deny_unknown_fields)]
+struct Foo {
+    #[serde(with = "module")]
+    foo: u32,
+}
+
+#[derive(Camo)]
+enum Bar {
+    #[serde(other)]
+    Baz,
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+use camo_derive::Camo;
+
+#[derive(Camo)]
+#[camo(strict)]
+struct Foo<'a> {
+    #[serde(rename = "fooBar", with = "module")]
+    foo: u32,
+    #[serde(serialize_with = "path", deserialize_with = "path")]
+    bar: u32,
+    #[serde(borrow, bound = "T: Clone")]
+    baz: &'a str,
+    #[serde(getter = "Foo::qux")]
+    qux: u32,
+}
+
+#[derive(Camo)]
+#[camo(strict)]
+struct Bar(#[serde(with = "module")] u32, #[serde(skip, default)] u32);
+
+fn main() {}
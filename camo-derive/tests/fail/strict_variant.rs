@@ -0,0 +1,16 @@
+use camo_derive::Camo;
+
+#[derive(Camo)]
+#[camo(strict)]
+enum Foo {
+    #[serde(rename = "foo", with = "module")]
+    Foo(u32),
+    #[serde(other)]
+    Bar,
+    #[serde(serialize_with = "path", deserialize_with = "path", bound = "")]
+    Baz(u32),
+    #[serde(borrow)]
+    Qux { #[serde(default, with = "module")] qux: u32 },
+}
+
+fn main() {}
@@ -1,6 +0,0 @@
-use camo_derive::Camo;
-
-#[derive(Camo)]
-struct Foo(i32, i32);
-
-fn main() {}
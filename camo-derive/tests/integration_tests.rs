@@ -6,19 +6,52 @@ fn tests() {
     cases.pass("tests/pass/struct.rs");
     cases.pass("tests/pass/enum.rs");
     cases.pass("tests/pass/enum_named_fields.rs");
+    cases.pass("tests/pass/enum_unnamed_fields.rs");
+    cases.pass("tests/pass/enum_discriminant.rs");
     cases.pass("tests/pass/generics.rs");
+    cases.pass("tests/pass/internally_tagged.rs");
     cases.pass("tests/pass/newtype.rs");
+    cases.pass("tests/pass/tuple.rs");
+    cases.pass("tests/pass/struct_unnamed_fields.rs");
+    cases.pass("tests/pass/unit_struct.rs");
     cases.pass("tests/pass/serde_attributes_container.rs");
     cases.pass("tests/pass/serde_attributes_variant.rs");
-    cases.compile_fail("tests/fail/bounded_generics.rs");
+    cases.pass("tests/pass/serde_attributes_field.rs");
+    cases.pass("tests/pass/serde_untagged.rs");
+    cases.pass("tests/pass/serde_proxy.rs");
+    cases.pass("tests/pass/registry.rs");
+    cases.pass("tests/pass/identity.rs");
+    cases.pass("tests/pass/std_types.rs");
+    cases.pass("tests/pass/serde_direction.rs");
+    cases.pass("tests/pass/arrays.rs");
+    cases.pass("tests/pass/generic_bounds.rs");
+    cases.pass("tests/pass/docs.rs");
+    cases.pass("tests/pass/camo_attributes.rs");
+    cases.pass("tests/pass/camo_overrides.rs");
+    cases.pass("tests/pass/strict.rs");
+    #[cfg(feature = "strict")]
+    cases.pass("tests/pass/strict_feature.rs");
+    #[cfg(not(feature = "strict"))]
+    cases.pass("tests/pass/strict_disabled.rs");
+    cases.pass("tests/pass/workarounds.rs");
     cases.compile_fail("tests/fail/union.rs");
-    cases.compile_fail("tests/fail/const_generics.rs");
-    cases.compile_fail("tests/fail/explicit_discriminant.rs");
-    cases.compile_fail("tests/fail/enum_multiple_unnamed_fields.rs");
-    cases.compile_fail("tests/fail/struct_unnamed_fields.rs");
+    cases.compile_fail("tests/fail/non_literal_discriminant.rs");
+    cases.compile_fail("tests/fail/repr_non_unit_variant.rs");
     cases.compile_fail("tests/fail/function_types.rs");
+    cases.compile_fail("tests/fail/internally_tagged_tuple_variant.rs");
     cases.compile_fail("tests/fail/macros.rs");
+    cases.compile_fail("tests/fail/multiple_errors.rs");
     cases.compile_fail("tests/fail/self_qualified_types.rs");
     cases.compile_fail("tests/fail/trait_object.rs");
     cases.compile_fail("tests/fail/serde_error.rs");
+    cases.compile_fail("tests/fail/serde_invalid_into.rs");
+    cases.compile_fail("tests/fail/camo_unknown_attribute.rs");
+    cases.compile_fail("tests/fail/camo_unnamed_field_attribute.rs");
+    cases.compile_fail("tests/fail/camo_misplaced_attribute.rs");
+    cases.compile_fail("tests/fail/camo_invalid_override.rs");
+    cases.compile_fail("tests/fail/strict_container.rs");
+    cases.compile_fail("tests/fail/strict_field.rs");
+    cases.compile_fail("tests/fail/strict_variant.rs");
+    #[cfg(feature = "strict")]
+    cases.compile_fail("tests/fail/strict_feature.rs");
 }
@@ -0,0 +1,46 @@
+use camo::core::{Camo as _, Container, ContainerAttributes, Item, Struct, Visibility, StructContent, UnnamedField, FieldAttributes, Type, PathSegment, TypePath, ArrayType, Docs};
+use camo_derive::Camo;
+
+const LENGTH: usize = 4;
+
+#[derive(Camo)]
+struct Foo([u8; 32], [f32; (3)], [i32; LENGTH]);
+
+fn main() {
+    let foo = Foo::camo();
+
+    let path = |name| {
+        Type::Path(TypePath::from([PathSegment {
+            name,
+            arguments: Vec::new(),
+        }]))
+    };
+
+    assert_eq!(
+        foo,
+        Container {
+            module_path: module_path!(),
+            attributes: ContainerAttributes::default(),
+            docs: Docs::default(),
+            item: Item::Struct(Struct {
+                visibility: Visibility::None,
+                name: "Foo",
+                parameters: Vec::new(),
+                content: StructContent::UnnamedFields(Vec::from([
+                    UnnamedField {
+                        attributes: FieldAttributes::default(),
+                        ty: Type::Array(ArrayType::with_length(path("u8"), 32)),
+                    },
+                    UnnamedField {
+                        attributes: FieldAttributes::default(),
+                        ty: Type::Array(ArrayType::with_length(path("f32"), 3)),
+                    },
+                    UnnamedField {
+                        attributes: FieldAttributes::default(),
+                        ty: Type::Array(ArrayType::from(path("i32"))),
+                    },
+                ])),
+            }),
+        }
+    );
+}
@@ -0,0 +1,172 @@
+use camo::core::{Camo as _, Container, ContainerAttributes, Item, Struct, Visibility, StructContent, FieldAttributes, NamedField, UnnamedField, Type, PathSegment, TypePath, Docs, Enum, Variant, VariantAttributes, VariantContent, BuiltinType};
+use camo_derive::Camo;
+
+struct Timestamp;
+
+#[derive(Camo)]
+#[camo(rename = "UserDto")]
+#[camo(export = false)]
+pub struct User {
+    #[camo(rename = "displayName")]
+    name: String,
+    #[camo(skip)]
+    password: String,
+    #[camo(foreign = "dates::Timestamp")]
+    created: Timestamp,
+}
+
+#[derive(Camo)]
+struct Entry(String, #[camo(skip)] u64, #[camo(foreign = "dates::Timestamp")] Timestamp);
+
+#[derive(Camo)]
+#[camo(repr, rename = "Level")]
+enum Priority {
+    #[camo(rename = "LOW")]
+    Low,
+    #[camo(skip)]
+    Internal,
+}
+
+fn main() {
+    assert_eq!(
+        User::camo(),
+        Container {
+            module_path: module_path!(),
+            attributes: ContainerAttributes {
+                camo_rename: Some("UserDto"),
+                export: Some(false),
+                ..ContainerAttributes::default()
+            },
+            docs: Docs::default(),
+            item: Item::Struct(Struct {
+                visibility: Visibility::Pub,
+                name: "User",
+                parameters: Vec::new(),
+                content: StructContent::NamedFields(Vec::from([
+                    NamedField {
+                        attributes: FieldAttributes {
+                            camo_rename: Some("displayName"),
+                            ..FieldAttributes::default()
+                        },
+                        docs: Docs::default(),
+                        name: "name",
+                        ty: Type::Builtin(BuiltinType::String),
+                    },
+                    NamedField {
+                        attributes: FieldAttributes {
+                            camo_skip: true,
+                            ..FieldAttributes::default()
+                        },
+                        docs: Docs::default(),
+                        name: "password",
+                        ty: Type::Builtin(BuiltinType::String),
+                    },
+                    NamedField {
+                        attributes: FieldAttributes {
+                            foreign: true,
+                            ..FieldAttributes::default()
+                        },
+                        docs: Docs::default(),
+                        name: "created",
+                        ty: Type::Path(TypePath::from([
+                            PathSegment {
+                                name: "dates",
+                                arguments: Vec::new(),
+                            },
+                            PathSegment {
+                                name: "Timestamp",
+                                arguments: Vec::new(),
+                            },
+                        ])),
+                    },
+                ])),
+            }),
+        }
+    );
+
+    assert_eq!(
+        Priority::camo(),
+        Container {
+            module_path: module_path!(),
+            attributes: ContainerAttributes {
+                repr: true,
+                camo_rename: Some("Level"),
+                ..ContainerAttributes::default()
+            },
+            docs: Docs::default(),
+            item: Item::Enum(Enum {
+                visibility: Visibility::None,
+                name: "Priority",
+                parameters: Vec::new(),
+                variants: Vec::from([
+                    Variant {
+                        attributes: VariantAttributes {
+                            camo_rename: Some("LOW"),
+                            ..VariantAttributes::default()
+                        },
+                        docs: Docs::default(),
+                        name: "Low",
+                        discriminant: None,
+                        content: VariantContent::Unit,
+                    },
+                    Variant {
+                        attributes: VariantAttributes {
+                            camo_skip: true,
+                            ..VariantAttributes::default()
+                        },
+                        docs: Docs::default(),
+                        name: "Internal",
+                        discriminant: None,
+                        content: VariantContent::Unit,
+                    },
+                ]),
+            }),
+        }
+    );
+
+    assert_eq!(
+        Entry::camo(),
+        Container {
+            module_path: module_path!(),
+            attributes: ContainerAttributes::default(),
+            docs: Docs::default(),
+            item: Item::Struct(Struct {
+                visibility: Visibility::None,
+                name: "Entry",
+                parameters: Vec::new(),
+                content: StructContent::UnnamedFields(Vec::from([
+                    UnnamedField {
+                        attributes: FieldAttributes::default(),
+                        ty: Type::Builtin(BuiltinType::String),
+                    },
+                    UnnamedField {
+                        attributes: FieldAttributes {
+                            camo_skip: true,
+                            ..FieldAttributes::default()
+                        },
+                        ty: Type::Path(TypePath::from([PathSegment {
+                            name: "u64",
+                            arguments: Vec::new(),
+                        }])),
+                    },
+                    UnnamedField {
+                        attributes: FieldAttributes {
+                            foreign: true,
+                            ..FieldAttributes::default()
+                        },
+                        ty: Type::Path(TypePath::from([
+                            PathSegment {
+                                name: "dates",
+                                arguments: Vec::new(),
+                            },
+                            PathSegment {
+                                name: "Timestamp",
+                                arguments: Vec::new(),
+                            },
+                        ])),
+                    },
+                ])),
+            }),
+        }
+    );
+}
@@ -0,0 +1,127 @@
+use camo::core::{Camo as _, Container, ContainerAttributes, Item, Struct, Visibility, StructContent, FieldAttributes, NamedField, UnnamedField, ReferenceType, Lifetime, VariantContent, Type, Docs, BuiltinType, MapType, SequenceType, TypePath, PathSegment};
+use camo_derive::Camo;
+
+pub trait Event {}
+
+macro_rules! id {
+    () => {
+        u64
+    };
+}
+
+#[derive(Camo)]
+pub struct Log {
+    #[camo(as = "std::collections::HashMap<String, String>")]
+    event: Box<dyn Event>,
+    #[camo(typescript = "(event: Event) => void")]
+    handler: fn(&dyn Event),
+    #[camo(as = "Vec<u64>", typescript = "bigint[]")]
+    ids: Vec<id!()>,    #[camo(as = "&str")]
+    name: Box<dyn Event>,
+}
+
+#[derive(Camo)]
+pub enum Message {
+    Event(#[camo(typescript = "Record<string, unknown>")] Box<dyn Event>),
+    Ids(#[camo(as = "Vec<u64>")] Vec<id!()>, #[camo(foreign = "Timestamp")] u64),
+}
+
+fn main() {
+    assert_eq!(
+        Log::camo(),
+        Container {
+            module_path: module_path!(),
+            attributes: ContainerAttributes::default(),
+            docs: Docs::default(),
+            item: Item::Struct(Struct {
+                visibility: Visibility::Pub,
+                name: "Log",
+                parameters: Vec::new(),
+                content: StructContent::NamedFields(Vec::from([
+                    NamedField {
+                        attributes: FieldAttributes::default(),
+                        docs: Docs::default(),
+                        name: "event",
+                        ty: Type::Map(MapType {
+                            key: Box::new(Type::Builtin(BuiltinType::String)),
+                            value: Box::new(Type::Builtin(BuiltinType::String)),
+                        }),
+                    },
+                    NamedField {
+                        attributes: FieldAttributes {
+                            typescript: Some("(event: Event) => void"),
+                            ..FieldAttributes::default()
+                        },
+                        docs: Docs::default(),
+                        name: "handler",
+                        ty: Type::Opaque,
+                    },
+                    NamedField {
+                        attributes: FieldAttributes {
+                            typescript: Some("bigint[]"),
+                            ..FieldAttributes::default()
+                        },
+                        docs: Docs::default(),
+                        name: "ids",
+                        ty: Type::Sequence(SequenceType::from(Type::Path(TypePath::from([
+                            PathSegment {
+                                name: "u64",
+                                arguments: Vec::new(),
+                            },
+                        ])))),
+                    },
+                    NamedField {
+                        attributes: FieldAttributes::default(),
+                        docs: Docs::default(),
+                        name: "name",
+                        ty: Type::Reference(ReferenceType {
+                            lifetime: Lifetime {
+                                name: String::from("_"),
+                            },
+                            ty: Box::new(Type::Builtin(BuiltinType::String)),
+                        }),
+                    },
+                ])),
+            }),
+        }
+    );
+
+    let variants = match Message::camo().item {
+        Item::Enum(e) => e.variants,
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        variants[0].content,
+        VariantContent::Unnamed(Vec::from([UnnamedField {
+            attributes: FieldAttributes {
+                typescript: Some("Record<string, unknown>"),
+                ..FieldAttributes::default()
+            },
+            ty: Type::Opaque,
+        }]))
+    );
+    assert_eq!(
+        variants[1].content,
+        VariantContent::Unnamed(Vec::from([
+            UnnamedField {
+                attributes: FieldAttributes::default(),
+                ty: Type::Sequence(SequenceType::from(Type::Path(TypePath::from([
+                    PathSegment {
+                        name: "u64",
+                        arguments: Vec::new(),
+                    },
+                ])))),
+            },
+            UnnamedField {
+                attributes: FieldAttributes {
+                    foreign: true,
+                    ..FieldAttributes::default()
+                },
+                ty: Type::Path(TypePath::from([PathSegment {
+                    name: "Timestamp",
+                    arguments: Vec::new(),
+                }])),
+            },
+        ]))
+    );
+}
@@ -0,0 +1,108 @@
+use camo::core::{BuiltinType, Camo as _, Container, ContainerAttributes, Item, Struct, Visibility, StructContent, FieldAttributes, NamedField, Type, PathSegment, TypePath, Docs, Deprecation, Enum, Variant, VariantAttributes, VariantContent};
+use camo_derive::Camo;
+
+/// A user.
+///
+/// ```
+/// let user = User::default();
+/// ```
+#[derive(Camo)]
+#[deprecated(since = "0.2.0", note = "use `Account` instead")]
+pub struct User {
+    /// The name
+    /// of the user.
+    name: String,
+    #[doc(hidden)]
+    #[deprecated]
+    age: u32,
+}
+
+#[derive(Camo)]
+pub enum Direction {
+    #[doc = "  Upwards.  "]
+    #[deprecated = "go down instead"]
+    Up,
+}
+
+fn main() {
+    #[allow(deprecated)]
+    let user = User::camo();
+
+    assert_eq!(
+        user,
+        Container {
+            module_path: module_path!(),
+            attributes: ContainerAttributes::default(),
+            docs: Docs {
+                lines: Vec::from(["A user.", "", "```", "let user = User::default();", "```"]),
+                deprecated: Some(Deprecation {
+                    since: Some("0.2.0"),
+                    note: Some("use `Account` instead"),
+                }),
+            },
+            item: Item::Struct(Struct {
+                visibility: Visibility::Pub,
+                name: "User",
+                parameters: Vec::new(),
+                content: StructContent::NamedFields(
+                    Vec::from([
+                        NamedField {
+                            attributes: FieldAttributes::default(),
+                            docs: Docs {
+                                lines: Vec::from(["The name", "of the user."]),
+                                deprecated: None,
+                            },
+                            name: "name",
+                            ty: Type::Builtin(BuiltinType::String),
+                        },
+                        NamedField {
+                            attributes: FieldAttributes::default(),
+                            docs: Docs {
+                                lines: Vec::new(),
+                                deprecated: Some(Deprecation {
+                                    since: None,
+                                    note: None,
+                                }),
+                            },
+                            name: "age",
+                            ty: Type::Path(TypePath::from([PathSegment {
+                                name: "u32",
+                                arguments: Vec::new(),
+                            }])),
+                        },
+                    ]),
+                ),
+            }),
+        }
+    );
+
+    #[allow(deprecated)]
+    let direction = Direction::camo();
+
+    assert_eq!(
+        direction,
+        Container {
+            module_path: module_path!(),
+            attributes: ContainerAttributes::default(),
+            docs: Docs::default(),
+            item: Item::Enum(Enum {
+                visibility: Visibility::Pub,
+                name: "Direction",
+                parameters: Vec::new(),
+                variants: Vec::from([Variant {
+                    attributes: VariantAttributes::default(),
+                    docs: Docs {
+                        lines: Vec::from(["Upwards."]),
+                        deprecated: Some(Deprecation {
+                            since: None,
+                            note: Some("go down instead"),
+                        }),
+                    },
+                    name: "Up",
+                    discriminant: None,
+                    content: VariantContent::Unit,
+                }]),
+            }),
+        }
+    );
+}
@@ -0,0 +1,55 @@
+use camo::core::{Camo as _, Container, ContainerAttributes, Item, Enum, Visibility, Variant, VariantAttributes, VariantContent, Docs};
+use camo_derive::Camo;
+
+#[derive(Camo)]
+#[camo(repr)]
+#[repr(i8)]
+enum Status {
+    Active = 1,
+    Disabled,
+    Deleted = -1,
+}
+
+fn main() {
+    let status = Status::camo();
+
+    assert_eq!(
+        status,
+        Container {
+            module_path: module_path!(),
+            attributes: ContainerAttributes {
+                repr: true,
+                ..ContainerAttributes::default()
+            },
+            docs: Docs::default(),
+            item: Item::Enum(Enum {
+                visibility: Visibility::None,
+                name: "Status",
+                parameters: Vec::new(),
+                variants: Vec::from([
+                    Variant {
+                        attributes: VariantAttributes::default(),
+                        docs: Docs::default(),
+                        name: "Active",
+                        discriminant: Some(1),
+                        content: VariantContent::Unit,
+                    },
+                    Variant {
+                        attributes: VariantAttributes::default(),
+                        docs: Docs::default(),
+                        name: "Disabled",
+                        discriminant: None,
+                        content: VariantContent::Unit,
+                    },
+                    Variant {
+                        attributes: VariantAttributes::default(),
+                        docs: Docs::default(),
+                        name: "Deleted",
+                        discriminant: Some(-1),
+                        content: VariantContent::Unit,
+                    },
+                ]),
+            }),
+        }
+    );
+}
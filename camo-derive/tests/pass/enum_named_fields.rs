@@ -1,4 +1,4 @@
-use camo::core::{Camo as _, Container, ContainerAttributes, Item, Enum, Visibility, Variant, VariantAttributes, VariantContent, NamedField, Type, PathSegment, TypePath};
+use camo::core::{Camo as _, Container, ContainerAttributes, Item, Enum, Visibility, Variant, VariantAttributes, VariantContent, FieldAttributes, NamedField, Type, PathSegment, TypePath, Docs};
 use camo_derive::Camo;
 
 #[derive(Camo)]
@@ -12,7 +12,9 @@ fn main() {
     assert_eq!(
         foo,
         Container {
+            module_path: module_path!(),
             attributes: ContainerAttributes::default(),
+            docs: Docs::default(),
             item: Item::Enum(Enum {
                 visibility: Visibility::None,
                 name: "Foo",
@@ -20,9 +22,13 @@ fn main() {
                 variants: Vec::from([
                     Variant {
                         attributes: VariantAttributes::default(),
+                        docs: Docs::default(),
                         name: "One",
+                        discriminant: None,
                         content: VariantContent::Named(Vec::from([
                             NamedField {
+                                attributes: FieldAttributes::default(),
+                                docs: Docs::default(),
                                 name: "a",
                                 ty: Type::Path(TypePath::from([PathSegment {
                                     name: "i32",
@@ -30,6 +36,8 @@ fn main() {
                                 }])),
                             },
                             NamedField {
+                                attributes: FieldAttributes::default(),
+                                docs: Docs::default(),
                                 name: "b",
                                 ty: Type::Path(TypePath::from([PathSegment {
                                     name: "i32",
@@ -1,4 +1,4 @@
-use camo::core::{Camo as _, Container, ContainerAttributes, Item, Enum, Visibility, Variant, VariantAttributes, VariantContent, Type, PathSegment, TypePath};
+use camo::core::{Camo as _, Container, ContainerAttributes, Item, Enum, Visibility, Variant, VariantAttributes, VariantContent, UnnamedField, Type, PathSegment, TypePath, Docs};
 use camo_derive::Camo;
 
 struct Bar {
@@ -17,7 +17,9 @@ fn main() {
     assert_eq!(
         foo,
         Container {
+            module_path: module_path!(),
             attributes: ContainerAttributes::default(),
+            docs: Docs::default(),
             item: Item::Enum(Enum {
                 visibility: Visibility::Pub,
                 name: "Foo",
@@ -25,19 +27,23 @@ fn main() {
                 variants: Vec::from([
                     Variant {
                         attributes: VariantAttributes::default(),
+                        docs: Docs::default(),
                         name: "U32",
-                        content: VariantContent::Unnamed(Type::Path(TypePath::from([PathSegment {
+                        discriminant: None,
+                        content: VariantContent::Unnamed(Vec::from([UnnamedField::from(Type::Path(TypePath::from([PathSegment {
                             name: "u32",
                             arguments: Vec::new(),
-                        }]))),
+                        }])))])),
                     },
                     Variant {
                         attributes: VariantAttributes::default(),
+                        docs: Docs::default(),
                         name: "Bar",
-                        content: VariantContent::Unnamed(Type::Path(TypePath::from([PathSegment {
+                        discriminant: None,
+                        content: VariantContent::Unnamed(Vec::from([UnnamedField::from(Type::Path(TypePath::from([PathSegment {
                             name: "Bar",
                             arguments: Vec::new(),
-                        }])))
+                        }])))]))
                     },
                 ]),
             }),
@@ -0,0 +1,49 @@
+use camo::core::{Camo as _, Container, ContainerAttributes, Item, Enum, Visibility, Variant, VariantAttributes, VariantContent, UnnamedField, FieldAttributes, Type, PathSegment, TypePath, Docs};
+use camo_derive::Camo;
+
+#[derive(Camo)]
+enum Foo {
+    Point(i32, i32),
+}
+
+fn main() {
+    let foo = Foo::camo();
+
+    assert_eq!(
+        foo,
+        Container {
+            module_path: module_path!(),
+            attributes: ContainerAttributes::default(),
+            docs: Docs::default(),
+            item: Item::Enum(Enum {
+                visibility: Visibility::None,
+                name: "Foo",
+                parameters: Vec::new(),
+                variants: Vec::from([
+                    Variant {
+                        attributes: VariantAttributes::default(),
+                        docs: Docs::default(),
+                        name: "Point",
+                        discriminant: None,
+                        content: VariantContent::Unnamed(Vec::from([
+                            UnnamedField {
+                                attributes: FieldAttributes::default(),
+                                ty: Type::Path(TypePath::from([PathSegment {
+                                    name: "i32",
+                                    arguments: Vec::new(),
+                                }])),
+                            },
+                            UnnamedField {
+                                attributes: FieldAttributes::default(),
+                                ty: Type::Path(TypePath::from([PathSegment {
+                                    name: "i32",
+                                    arguments: Vec::new(),
+                                }])),
+                            },
+                        ])),
+                    },
+                ]),
+            }),
+        }
+    );
+}
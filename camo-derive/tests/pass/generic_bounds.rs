@@ -0,0 +1,123 @@
+use camo::core::{Camo as _, ConstParameter, Container, ContainerAttributes, GenericParameter, Item, Struct, Visibility, StructContent, FieldAttributes, NamedField, Type, TypeParameter, TypePath, PathSegment, ArrayType, BuiltinType, Docs};
+use camo_derive::Camo;
+
+#[derive(Camo)]
+struct Page<'a, T: Clone + ?Sized + 'a, U = String, const N: usize = 4>
+where
+    U: std::fmt::Debug,
+    &'a T: Copy,
+{
+    items: &'a T,
+    cursor: U,
+    previous: [u32; N],
+}
+
+// Bounds that cannot be described as paths are left out
+#[derive(Camo)]
+struct Source<I: Iterator<Item = u32> + Clone, F>
+where
+    F: Fn(u32) -> u32,
+{
+    items: Vec<u32>,
+    #[camo(skip)]
+    iter: I,
+    #[camo(skip)]
+    map: F,
+}
+
+fn path(name: &'static str) -> TypePath {
+    TypePath::from([PathSegment {
+        name,
+        arguments: Vec::new(),
+    }])
+}
+
+fn main() {
+    assert_eq!(
+        Page::<'static, u8>::camo(),
+        Container {
+            module_path: module_path!(),
+            attributes: ContainerAttributes::default(),
+            docs: Docs::default(),
+            item: Item::Struct(Struct {
+                visibility: Visibility::None,
+                name: "Page",
+                parameters: Vec::from([
+                    GenericParameter::Lifetime("a"),
+                    GenericParameter::Type(TypeParameter {
+                        name: "T",
+                        bounds: Vec::from([path("Clone")]),
+                        default: None,
+                    }),
+                    GenericParameter::Type(TypeParameter {
+                        name: "U",
+                        bounds: Vec::from([TypePath::from([
+                            PathSegment {
+                                name: "std",
+                                arguments: Vec::new(),
+                            },
+                            PathSegment {
+                                name: "fmt",
+                                arguments: Vec::new(),
+                            },
+                            PathSegment {
+                                name: "Debug",
+                                arguments: Vec::new(),
+                            },
+                        ])]),
+                        default: Some(Type::Builtin(BuiltinType::String)),
+                    }),
+                    GenericParameter::Const(ConstParameter {
+                        name: "N",
+                        ty: Type::Path(path("usize")),
+                    }),
+                ]),
+                content: StructContent::NamedFields(Vec::from([
+                    NamedField {
+                        attributes: FieldAttributes::default(),
+                        docs: Docs::default(),
+                        name: "items",
+                        ty: Type::Reference(camo::core::ReferenceType {
+                            lifetime: camo::core::Lifetime {
+                                name: String::from("a"),
+                            },
+                            ty: Box::new(Type::Path(path("T"))),
+                        }),
+                    },
+                    NamedField {
+                        attributes: FieldAttributes::default(),
+                        docs: Docs::default(),
+                        name: "cursor",
+                        ty: Type::Path(path("U")),
+                    },
+                    NamedField {
+                        attributes: FieldAttributes::default(),
+                        docs: Docs::default(),
+                        name: "previous",
+                        ty: Type::Array(ArrayType::from(Type::Path(path("u32")))),
+                    },
+                ])),
+            }),
+        }
+    );
+
+    let parameters = match Source::<std::vec::IntoIter<u32>, fn(u32) -> u32>::camo().item {
+        Item::Struct(s) => s.parameters,
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        parameters,
+        Vec::from([
+            GenericParameter::Type(TypeParameter {
+                name: "I",
+                bounds: Vec::from([path("Clone")]),
+                default: None,
+            }),
+            GenericParameter::Type(TypeParameter {
+                name: "F",
+                bounds: Vec::new(),
+                default: None,
+            }),
+        ])
+    );
+}
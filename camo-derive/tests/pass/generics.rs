@@ -1,4 +1,4 @@
-use camo::core::{Camo as _, Container, ContainerAttributes, Item, Struct, Visibility, GenericParameter, StructContent, NamedField, Type, TypePath, PathSegment, ReferenceType, Lifetime};
+use camo::core::{Camo as _, Container, ContainerAttributes, Item, Struct, Visibility, GenericParameter, TypeParameter, StructContent, FieldAttributes, NamedField, Type, TypePath, PathSegment, ReferenceType, Lifetime, BuiltinType, Docs};
 use camo_derive::Camo;
 
 #[derive(Camo)]
@@ -14,17 +14,21 @@ fn main() {
     assert_eq!(
         foo,
         Container {
+            module_path: module_path!(),
             attributes: ContainerAttributes::default(),
+            docs: Docs::default(),
             item: Item::Struct(Struct {
                 visibility: Visibility::None,
                 name: "Foo",
                 parameters: Vec::from([
                     GenericParameter::Lifetime("a"),
-                    GenericParameter::Type("T"),
+                    GenericParameter::Type(TypeParameter::from("T")),
                 ]),
                 content: StructContent::NamedFields(
                     Vec::from([
                         NamedField {
+                            attributes: FieldAttributes::default(),
+                            docs: Docs::default(),
                             name: "foo",
                             ty: Type::Path(TypePath::from([PathSegment {
                                 name: "T",
@@ -32,15 +36,14 @@ fn main() {
                             }])),
                         },
                         NamedField {
+                            attributes: FieldAttributes::default(),
+                            docs: Docs::default(),
                             name: "bar",
                             ty: Type::Reference(ReferenceType {
                                 lifetime: Lifetime {
                                     name: String::from("a"),
                                 },
-                                ty: Box::new(Type::Path(TypePath::from([PathSegment {
-                                    name: "str",
-                                    arguments: Vec::new(),
-                                }]))),
+                                ty: Box::new(Type::Builtin(BuiltinType::String)),
                             }),
                         },
                     ])
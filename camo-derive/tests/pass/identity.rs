@@ -0,0 +1,77 @@
+use camo::core::{Camo as _, PathSegment, TypeIdentity, TypePath, TypeRegistry};
+use camo_derive::Camo;
+
+mod admin {
+    use camo_derive::Camo;
+
+    #[derive(Camo)]
+    pub struct User {
+        pub level: u8,
+    }
+}
+
+mod guest {
+    use camo_derive::Camo;
+
+    #[derive(Camo)]
+    pub struct User {
+        pub name: String,
+    }
+
+    #[derive(Camo)]
+    pub struct Visit {
+        pub by: User,
+        pub admin: super::admin::User,
+    }
+}
+
+#[derive(Camo)]
+struct Users {
+    admin: admin::User,
+    guest: crate::guest::User,
+    visit: guest::Visit,
+}
+
+fn path(names: &[&'static str]) -> TypePath {
+    TypePath {
+        segments: names
+            .iter()
+            .map(|&name| PathSegment {
+                name,
+                arguments: Vec::new(),
+            })
+            .collect(),
+    }
+}
+
+fn main() {
+    let admin = TypeIdentity {
+        module_path: concat!(module_path!(), "::admin"),
+        name: "User",
+    };
+    let guest = TypeIdentity {
+        module_path: concat!(module_path!(), "::guest"),
+        name: "User",
+    };
+
+    assert_eq!(admin::User::identity(), admin);
+    assert_eq!(guest::User::identity(), guest);
+    assert_eq!(admin::User::camo().identity(), admin);
+    assert_eq!(admin.crate_name(), module_path!());
+
+    assert_eq!(Users::resolve(&path(&["admin", "User"])), Some(admin));
+    assert_eq!(Users::resolve(&path(&["crate", "guest", "User"])), Some(guest));
+    assert_eq!(guest::Visit::resolve(&path(&["User"])), Some(guest));
+    assert_eq!(guest::Visit::resolve(&path(&["super", "admin", "User"])), Some(admin));
+    assert_eq!(Users::resolve(&path(&["User"])), None);
+    assert_eq!(admin::User::resolve(&path(&["u8"])), None);
+
+    let mut registry = TypeRegistry::new();
+    registry.add::<Users>();
+    assert_eq!(registry.len(), 4);
+
+    let users = registry.get(&Users::identity()).unwrap();
+    let visit = registry.resolve(users, &path(&["guest", "Visit"])).unwrap();
+    let by = registry.resolve(visit, &path(&["User"])).unwrap();
+    assert_eq!(by.identity(), guest);
+}
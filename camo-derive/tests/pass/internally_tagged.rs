@@ -0,0 +1,35 @@
+use camo::core::{Camo as _, Item, TupleType, Type, VariantContent};
+use camo_derive::Camo;
+
+#[derive(Camo)]
+struct Ping;
+
+#[derive(Camo)]
+struct Pong(u32);
+
+#[derive(Camo)]
+#[serde(tag = "type")]
+enum Msg<T> {
+    Ping(Ping),
+    Pong(Pong),
+    Other(T),
+}
+
+fn main() {
+    let variants = match Msg::<Ping>::camo().item {
+        Item::Enum(e) => e.variants,
+        _ => unreachable!(),
+    };
+    let types: Vec<_> = variants
+        .into_iter()
+        .map(|variant| match variant.content {
+            VariantContent::Unnamed(fields) => fields[0].ty.clone(),
+            _ => unreachable!(),
+        })
+        .collect();
+    // A unit struct only contributes the tag
+    assert_eq!(types[0], Type::Tuple(TupleType::unit()));
+    assert!(matches!(types[1], Type::Path(_)));
+    // Generic parameters are not known to be unit structs
+    assert!(matches!(types[2], Type::Path(_)));
+}
@@ -1,4 +1,4 @@
-use camo::core::{Camo as _, Container, ContainerAttributes, Item, Struct, Visibility, StructContent, UnnamedField, Type, PathSegment, TypePath};
+use camo::core::{Camo as _, Container, ContainerAttributes, Item, Struct, Visibility, StructContent, UnnamedField, FieldAttributes, Type, PathSegment, TypePath, Docs};
 use camo_derive::Camo;
 
 #[derive(Camo)]
@@ -10,17 +10,20 @@ fn main() {
     assert_eq!(
         foo,
         Container {
+            module_path: module_path!(),
             attributes: ContainerAttributes::default(),
+            docs: Docs::default(),
             item: Item::Struct(Struct {
                 visibility: Visibility::None,
                 name: "Foo",
                 parameters: Vec::new(),
-                content: StructContent::UnnamedField(UnnamedField {
+                content: StructContent::UnnamedFields(Vec::from([UnnamedField {
+                    attributes: FieldAttributes::default(),
                     ty: Type::Path(TypePath::from([PathSegment {
                         name: "i32",
                         arguments: Vec::new(),
                     }])),
-                }),
+                }])),
             }),
         }
     );
@@ -0,0 +1,45 @@
+use camo::core::TypeRegistry;
+use camo_derive::Camo;
+
+#[derive(Camo)]
+struct Node<'a, T> {
+    value: T,
+    label: &'a str,
+    children: Vec<Node<'a, T>>,
+    next: Option<Box<Node<'a, T>>>,
+    kind: Kind,
+}
+
+#[derive(Camo)]
+enum Kind {
+    Leaf,
+    Branch(Vec<(Weight, Kind)>),
+}
+
+#[derive(Camo)]
+struct Weight(u8);
+
+#[derive(Camo)]
+struct Tree {
+    root: Node<'static, Weight>,
+    weights: [Weight; 4],
+}
+
+fn main() {
+    let mut registry = TypeRegistry::new();
+    registry.add::<Tree>();
+
+    let names: Vec<_> = registry
+        .containers()
+        .into_iter()
+        .map(|container| container.item.name())
+        .collect();
+    assert_eq!(names, ["Weight", "Kind", "Node", "Tree"]);
+
+    let cycles: Vec<Vec<_>> = registry
+        .cycles()
+        .into_iter()
+        .map(|cycle| cycle.into_iter().map(|container| container.item.name()).collect())
+        .collect();
+    assert_eq!(cycles, [vec!["Kind"], vec!["Node"]]);
+}
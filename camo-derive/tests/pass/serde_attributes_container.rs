@@ -1,4 +1,4 @@
-use camo::core::{Camo as _, Container, ContainerAttributes, RenameRule, Item, Struct, Enum, Visibility, StructContent, NamedField, Variant, VariantAttributes, VariantContent, Type, PathSegment, TypePath};
+use camo::core::{Camo as _, Container, ContainerAttributes, RenameRule, Item, Struct, Enum, Visibility, StructContent, FieldAttributes, NamedField, Variant, VariantAttributes, VariantContent, UnnamedField, Type, PathSegment, TypePath, Docs};
 use camo_derive::Camo;
 use serde::Serialize;
 
@@ -28,11 +28,13 @@ fn main() {
     assert_eq!(
         foo,
         Container {
+            module_path: module_path!(),
             attributes: ContainerAttributes {
-                rename: Some(RenameRule::LowerCase),
+                rename: Some("lowercase"),
                 rename_all: Some(RenameRule::CamelCase),
                 ..ContainerAttributes::default()
             },
+            docs: Docs::default(),
             item: Item::Struct(Struct {
                 visibility: Visibility::None,
                 name: "Foo",
@@ -40,6 +42,8 @@ fn main() {
                 content: StructContent::NamedFields(
                     Vec::from([
                         NamedField {
+                            attributes: FieldAttributes::default(),
+                            docs: Docs::default(),
                             name: "foo",
                             ty: Type::Path(TypePath::from([PathSegment {
                                 name: "u32",
@@ -47,6 +51,8 @@ fn main() {
                             }])),
                         },
                         NamedField {
+                            attributes: FieldAttributes::default(),
+                            docs: Docs::default(),
                             name: "bar",
                             ty: Type::Path(TypePath::from([PathSegment {
                                 name: "bool",
@@ -54,6 +60,8 @@ fn main() {
                             }])),
                         },
                         NamedField {
+                            attributes: FieldAttributes::default(),
+                            docs: Docs::default(),
                             name: "baz",
                             ty: Type::Path(TypePath::from([PathSegment {
                                 name: "char",
@@ -71,11 +79,13 @@ fn main() {
     assert_eq!(
         bar,
         Container {
+            module_path: module_path!(),
             attributes: ContainerAttributes {
                 tag: Some("type"),
                 content: Some("content"),
                 ..ContainerAttributes::default()
             },
+            docs: Docs::default(),
             item: Item::Enum(Enum {
                 visibility: Visibility::None,
                 name: "Bar",
@@ -83,19 +93,23 @@ fn main() {
                 variants: Vec::from([
                     Variant {
                         attributes: VariantAttributes::default(),
+                        docs: Docs::default(),
                         name: "VariantOne",
-                        content: VariantContent::Unnamed(Type::Path(TypePath::from([PathSegment {
+                        discriminant: None,
+                        content: VariantContent::Unnamed(Vec::from([UnnamedField::from(Type::Path(TypePath::from([PathSegment {
                             name: "u32",
                             arguments: Vec::new(),
-                        }])))
+                        }])))]))
                     },
                     Variant {
                         attributes: VariantAttributes::default(),
+                        docs: Docs::default(),
                         name: "VariantTwo",
-                        content: VariantContent::Unnamed(Type::Path(TypePath::from([PathSegment {
+                        discriminant: None,
+                        content: VariantContent::Unnamed(Vec::from([UnnamedField::from(Type::Path(TypePath::from([PathSegment {
                             name: "Baz",
                             arguments: Vec::new(),
-                        }])))
+                        }])))]))
                     },
                 ]),
             }),
@@ -0,0 +1,147 @@
+use camo::core::{Camo as _, Container, ContainerAttributes, Item, Struct, Visibility, StructContent, FieldAttributes, NamedField, UnnamedField, Type, PathSegment, TypePath, BuiltinType, Docs};
+use camo_derive::Camo;
+use serde::{Deserialize, Serialize};
+
+#[derive(Camo, Serialize, Deserialize)]
+struct Foo {
+    #[serde(rename = "ID")]
+    id: u64,
+    #[serde(skip)]
+    skipped: u8,
+    #[serde(skip_serializing, default)]
+    write_only: u8,
+    #[serde(skip_deserializing)]
+    read_only: u8,
+    #[serde(default = "default_name", skip_serializing_if = "String::is_empty")]
+    name: String,
+    #[serde(flatten)]
+    extra: Extra,
+}
+
+#[derive(Serialize, Deserialize, Default)]
+struct Extra {
+    value: u8,
+}
+
+fn default_name() -> String {
+    String::from("name")
+}
+
+#[derive(Camo, Serialize, Deserialize)]
+struct Pair(#[serde(skip_serializing)] u8, #[serde(skip_deserializing)] u8);
+
+fn main() {
+    let foo = Foo::camo();
+
+    let path = |name| {
+        Type::Path(TypePath::from([PathSegment {
+            name,
+            arguments: Vec::new(),
+        }]))
+    };
+
+    assert_eq!(
+        foo,
+        Container {
+            module_path: module_path!(),
+            attributes: ContainerAttributes::default(),
+            docs: Docs::default(),
+            item: Item::Struct(Struct {
+                visibility: Visibility::None,
+                name: "Foo",
+                parameters: Vec::new(),
+                content: StructContent::NamedFields(Vec::from([
+                    NamedField {
+                        attributes: FieldAttributes {
+                            rename: Some("ID"),
+                            ..FieldAttributes::default()
+                        },
+                        docs: Docs::default(),
+                        name: "id",
+                        ty: path("u64"),
+                    },
+                    NamedField {
+                        attributes: FieldAttributes {
+                            skip: true,
+                            ..FieldAttributes::default()
+                        },
+                        docs: Docs::default(),
+                        name: "skipped",
+                        ty: path("u8"),
+                    },
+                    NamedField {
+                        attributes: FieldAttributes {
+                            skip_serializing: true,
+                            default: true,
+                            ..FieldAttributes::default()
+                        },
+                        docs: Docs::default(),
+                        name: "write_only",
+                        ty: path("u8"),
+                    },
+                    NamedField {
+                        attributes: FieldAttributes {
+                            skip_deserializing: true,
+                            ..FieldAttributes::default()
+                        },
+                        docs: Docs::default(),
+                        name: "read_only",
+                        ty: path("u8"),
+                    },
+                    NamedField {
+                        attributes: FieldAttributes {
+                            default: true,
+                            skip_serializing_if: Some("String::is_empty"),
+                            ..FieldAttributes::default()
+                        },
+                        docs: Docs::default(),
+                        name: "name",
+                        ty: Type::Builtin(BuiltinType::String),
+                    },
+                    NamedField {
+                        attributes: FieldAttributes {
+                            flatten: true,
+                            ..FieldAttributes::default()
+                        },
+                        docs: Docs::default(),
+                        name: "extra",
+                        ty: path("Extra"),
+                    },
+                ])),
+            }),
+        }
+    );
+
+    let fields = match Pair::camo().item {
+        Item::Struct(Struct {
+            content: StructContent::UnnamedFields(fields),
+            ..
+        }) => fields,
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        fields,
+        Vec::from([
+            UnnamedField {
+                attributes: FieldAttributes {
+                    skip_serializing: true,
+                    ..FieldAttributes::default()
+                },
+                ty: Type::Path(TypePath::from([PathSegment {
+                    name: "u8",
+                    arguments: Vec::new(),
+                }])),
+            },
+            UnnamedField {
+                attributes: FieldAttributes {
+                    skip_deserializing: true,
+                    ..FieldAttributes::default()
+                },
+                ty: Type::Path(TypePath::from([PathSegment {
+                    name: "u8",
+                    arguments: Vec::new(),
+                }])),
+            },
+        ])
+    );
+}
@@ -1,4 +1,4 @@
-use camo::core::{Camo as _, Container, ContainerAttributes, RenameRule, Item, Enum, Visibility, Variant, VariantAttributes, VariantContent, NamedField, Type, PathSegment, TypePath};
+use camo::core::{Camo as _, Container, ContainerAttributes, RenameRule, Item, Enum, Visibility, Variant, VariantAttributes, VariantContent, UnnamedField, FieldAttributes, NamedField, Type, PathSegment, TypePath, Docs};
 use camo_derive::Camo;
 use serde::Serialize;
 
@@ -7,6 +7,7 @@ use serde::Serialize;
 enum Bar {
     #[serde(rename_all = "camelCase")]
     VariantOne { field_one: i32, field_two: u32 },
+    #[serde(rename = "variant_two")]
     VariantTwo(Baz),
 }
 
@@ -21,11 +22,13 @@ fn main() {
     assert_eq!(
         bar,
         Container {
+            module_path: module_path!(),
             attributes: ContainerAttributes {
                 tag: Some("type"),
                 content: Some("content"),
                 ..ContainerAttributes::default()
             },
+            docs: Docs::default(),
             item: Item::Enum(Enum {
                 visibility: Visibility::None,
                 name: "Bar",
@@ -36,9 +39,13 @@ fn main() {
                             rename_all: Some(RenameRule::CamelCase),
                             ..VariantAttributes::default()
                         },
+                        docs: Docs::default(),
                         name: "VariantOne",
+                        discriminant: None,
                         content: VariantContent::Named(Vec::from([
                             NamedField {
+                                attributes: FieldAttributes::default(),
+                                docs: Docs::default(),
                                 name: "field_one",
                                 ty: Type::Path(TypePath::from([PathSegment {
                                     name: "i32",
@@ -46,6 +53,8 @@ fn main() {
                                 }])),
                             },
                             NamedField {
+                                attributes: FieldAttributes::default(),
+                                docs: Docs::default(),
                                 name: "field_two",
                                 ty: Type::Path(TypePath::from([PathSegment {
                                     name: "u32",
@@ -55,12 +64,17 @@ fn main() {
                         ])),
                     },
                     Variant {
-                        attributes: VariantAttributes::default(),
+                        attributes: VariantAttributes {
+                            rename: Some("variant_two"),
+                            ..VariantAttributes::default()
+                        },
+                        docs: Docs::default(),
                         name: "VariantTwo",
-                        content: VariantContent::Unnamed(Type::Path(TypePath::from([PathSegment {
+                        discriminant: None,
+                        content: VariantContent::Unnamed(Vec::from([UnnamedField::from(Type::Path(TypePath::from([PathSegment {
                             name: "Baz",
                             arguments: Vec::new(),
-                        }])))
+                        }])))]))
                     },
                 ]),
             }),
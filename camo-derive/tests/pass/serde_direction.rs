@@ -0,0 +1,92 @@
+use camo::core::{Camo as _, Container, ContainerAttributes, RenameRule, Item, Enum, Visibility, Variant, VariantAttributes, VariantContent, FieldAttributes, NamedField, Type, PathSegment, TypePath, Docs};
+use camo_derive::Camo;
+use serde::{Deserialize, Serialize};
+
+#[derive(Camo, Serialize, Deserialize)]
+#[serde(
+    rename(serialize = "EventOut", deserialize = "EventIn"),
+    rename_all(deserialize = "snake_case")
+)]
+enum Event {
+    #[serde(
+        rename(serialize = "created"),
+        alias = "new",
+        alias = "added",
+        rename_all(serialize = "camelCase")
+    )]
+    Created {
+        #[serde(rename(deserialize = "id"), alias = "ident")]
+        user_id: u64,
+    },
+    #[serde(skip_deserializing)]
+    Deleted,
+    #[serde(skip)]
+    Internal,
+}
+
+fn main() {
+    assert_eq!(
+        Event::camo(),
+        Container {
+            module_path: module_path!(),
+            attributes: ContainerAttributes {
+                rename_serialize: Some("EventOut"),
+                rename_deserialize: Some("EventIn"),
+                rename_all_deserialize: Some(RenameRule::SnakeCase),
+                ..ContainerAttributes::default()
+            },
+            docs: Docs::default(),
+            item: Item::Enum(Enum {
+                visibility: Visibility::None,
+                name: "Event",
+                parameters: Vec::new(),
+                variants: Vec::from([
+                    Variant {
+                        attributes: VariantAttributes {
+                            rename_serialize: Some("created"),
+                            aliases: Vec::from(["new", "added"]),
+                            rename_all_serialize: Some(RenameRule::CamelCase),
+                            ..VariantAttributes::default()
+                        },
+                        docs: Docs::default(),
+                        name: "Created",
+                        discriminant: None,
+                        content: VariantContent::Named(Vec::from([NamedField {
+                            attributes: FieldAttributes {
+                                rename_deserialize: Some("id"),
+                                aliases: Vec::from(["ident"]),
+                                ..FieldAttributes::default()
+                            },
+                            docs: Docs::default(),
+                            name: "user_id",
+                            ty: Type::Path(TypePath::from([PathSegment {
+                                name: "u64",
+                                arguments: Vec::new(),
+                            }])),
+                        }])),
+                    },
+                    Variant {
+                        attributes: VariantAttributes {
+                            skip_deserializing: true,
+                            ..VariantAttributes::default()
+                        },
+                        docs: Docs::default(),
+                        name: "Deleted",
+                        discriminant: None,
+                        content: VariantContent::Unit,
+                    },
+                    Variant {
+                        attributes: VariantAttributes {
+                            skip: true,
+                            ..VariantAttributes::default()
+                        },
+                        docs: Docs::default(),
+                        name: "Internal",
+                        discriminant: None,
+                        content: VariantContent::Unit,
+                    },
+                ]),
+            }),
+        }
+    );
+}
@@ -0,0 +1,91 @@
+use camo::core::{Camo as _, Container, ContainerAttributes, Item, Struct, Visibility, StructContent, UnnamedField, Type, PathSegment, TypePath, PointerType, ReferenceType, Lifetime, BuiltinType, Docs};
+use camo_derive::Camo;
+use serde::{Deserialize, Serialize};
+use std::convert::TryFrom;
+
+#[derive(Camo, Serialize, Deserialize)]
+#[serde(transparent)]
+struct Id(u64);
+
+#[derive(Camo, Clone, Serialize, Deserialize)]
+#[serde(into = "String", try_from = "std::borrow::Cow<'static, str>")]
+struct Email(String);
+
+impl From<Email> for String {
+    fn from(value: Email) -> Self {
+        value.0
+    }
+}
+
+impl TryFrom<std::borrow::Cow<'static, str>> for Email {
+    type Error = String;
+
+    fn try_from(value: std::borrow::Cow<'static, str>) -> Result<Self, Self::Error> {
+        Ok(Email(value.into_owned()))
+    }
+}
+
+// The lifetime may be elided in the proxy types
+#[derive(Camo)]
+#[serde(into = "&str", from = "&'static str")]
+struct Name(String);
+
+fn main() {
+    let path = |name| {
+        Type::Path(TypePath::from([PathSegment {
+            name,
+            arguments: Vec::new(),
+        }]))
+    };
+
+    assert_eq!(
+        Id::camo(),
+        Container {
+            module_path: module_path!(),
+            attributes: ContainerAttributes {
+                transparent: true,
+                ..ContainerAttributes::default()
+            },
+            docs: Docs::default(),
+            item: Item::Struct(Struct {
+                visibility: Visibility::None,
+                name: "Id",
+                parameters: Vec::new(),
+                content: StructContent::UnnamedFields(Vec::from([UnnamedField::from(path("u64"))])),
+            }),
+        }
+    );
+
+    assert_eq!(
+        Email::camo(),
+        Container {
+            module_path: module_path!(),
+            attributes: ContainerAttributes {
+                into: Some(Type::Builtin(BuiltinType::String)),
+                try_from: Some(Type::Pointer(PointerType::from(Type::Builtin(
+                    BuiltinType::String,
+                )))),
+                ..ContainerAttributes::default()
+            },
+            docs: Docs::default(),
+            item: Item::Struct(Struct {
+                visibility: Visibility::None,
+                name: "Email",
+                parameters: Vec::new(),
+                content: StructContent::UnnamedFields(Vec::from([UnnamedField::from(Type::Builtin(BuiltinType::String))])),
+            }),
+        }
+    );
+
+    let reference = |lifetime: &str| {
+        Some(Type::Reference(ReferenceType {
+            lifetime: Lifetime {
+                name: String::from(lifetime),
+            },
+            ty: Box::new(Type::Builtin(BuiltinType::String)),
+        }))
+    };
+    let name = Name::camo();
+    assert_eq!(name.attributes.into, reference("_"));
+    assert_eq!(name.attributes.from, reference("static"));
+}
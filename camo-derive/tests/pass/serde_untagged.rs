@@ -0,0 +1,92 @@
+use camo::core::{Camo as _, Container, ContainerAttributes, Item, Enum, Visibility, Variant, VariantAttributes, VariantContent, UnnamedField, Type, PathSegment, TypePath, BuiltinType, Docs};
+use camo_derive::Camo;
+use serde::Serialize;
+
+#[derive(Camo, Serialize)]
+#[serde(untagged)]
+enum Value {
+    Number(i32),
+    Text(String),
+}
+
+#[derive(Camo, Serialize)]
+enum Event {
+    Ping,
+    #[serde(untagged)]
+    Other(String),
+}
+
+fn main() {
+    let path = |name| {
+        Vec::from([UnnamedField::from(Type::Path(TypePath::from([PathSegment {
+            name,
+            arguments: Vec::new(),
+        }])))])
+    };
+
+    assert_eq!(
+        Value::camo(),
+        Container {
+            module_path: module_path!(),
+            attributes: ContainerAttributes {
+                untagged: true,
+                ..ContainerAttributes::default()
+            },
+            docs: Docs::default(),
+            item: Item::Enum(Enum {
+                visibility: Visibility::None,
+                name: "Value",
+                parameters: Vec::new(),
+                variants: Vec::from([
+                    Variant {
+                        attributes: VariantAttributes::default(),
+                        docs: Docs::default(),
+                        name: "Number",
+                        discriminant: None,
+                        content: VariantContent::Unnamed(path("i32")),
+                    },
+                    Variant {
+                        attributes: VariantAttributes::default(),
+                        docs: Docs::default(),
+                        name: "Text",
+                        discriminant: None,
+                        content: VariantContent::Unnamed(Vec::from([UnnamedField::from(Type::Builtin(BuiltinType::String))])),
+                    },
+                ]),
+            }),
+        }
+    );
+
+    assert_eq!(
+        Event::camo(),
+        Container {
+            module_path: module_path!(),
+            attributes: ContainerAttributes::default(),
+            docs: Docs::default(),
+            item: Item::Enum(Enum {
+                visibility: Visibility::None,
+                name: "Event",
+                parameters: Vec::new(),
+                variants: Vec::from([
+                    Variant {
+                        attributes: VariantAttributes::default(),
+                        docs: Docs::default(),
+                        name: "Ping",
+                        discriminant: None,
+                        content: VariantContent::Unit,
+                    },
+                    Variant {
+                        attributes: VariantAttributes {
+                            untagged: true,
+                            ..VariantAttributes::default()
+                        },
+                        docs: Docs::default(),
+                        name: "Other",
+                        discriminant: None,
+                        content: VariantContent::Unnamed(Vec::from([UnnamedField::from(Type::Builtin(BuiltinType::String))])),
+                    },
+                ]),
+            }),
+        }
+    );
+}
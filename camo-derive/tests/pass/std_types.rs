@@ -0,0 +1,67 @@
+use camo::core::{Camo as _, Item, StructContent, Type, PathSegment, TypePath, BuiltinType, SequenceType, MapType, OptionalType, PointerType, TupleType, GenericArgument};
+use camo_derive::Camo;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashSet};
+use std::marker::PhantomData;
+use std::num::NonZeroU32;
+use std::rc::Rc;
+
+mod custom {
+    pub struct Vec<T>(pub T);
+}
+
+#[derive(Camo)]
+struct Foo<'a, T> {
+    text: Cow<'a, str>,
+    list: Vec<Option<T>>,
+    set: HashSet<Rc<String>>,
+    map: BTreeMap<u8, std::path::PathBuf>,
+    count: NonZeroU32,
+    marker: PhantomData<T>,
+    custom: custom::Vec<u8>,
+}
+
+fn path(name: &'static str) -> Type {
+    Type::Path(TypePath::from([PathSegment {
+        name,
+        arguments: Vec::new(),
+    }]))
+}
+
+fn main() {
+    let content = match Foo::<()>::camo().item {
+        Item::Struct(ty) => ty.content,
+        Item::Enum(_) => unreachable!(),
+    };
+    let types: Vec<Type> = match content {
+        StructContent::NamedFields(fields) => fields.into_iter().map(|field| field.ty).collect(),
+        _ => unreachable!(),
+    };
+
+    assert_eq!(
+        types,
+        Vec::from([
+            Type::Pointer(PointerType::from(Type::Builtin(BuiltinType::String))),
+            Type::Sequence(SequenceType::from(Type::Optional(OptionalType::from(path("T"))))),
+            Type::Sequence(SequenceType::from(Type::Pointer(PointerType::from(
+                Type::Builtin(BuiltinType::String),
+            )))),
+            Type::Map(MapType {
+                key: Box::new(path("u8")),
+                value: Box::new(Type::Builtin(BuiltinType::String)),
+            }),
+            Type::Builtin(BuiltinType::U32),
+            Type::Tuple(TupleType::unit()),
+            Type::Path(TypePath::from([
+                PathSegment {
+                    name: "custom",
+                    arguments: Vec::new(),
+                },
+                PathSegment {
+                    name: "Vec",
+                    arguments: Vec::from([GenericArgument::Type(path("u8"))]),
+                },
+            ])),
+        ])
+    );
+}
@@ -0,0 +1,57 @@
+use camo::core::{Camo as _, ContainerAttributes, FieldAttributes, Item, RenameRule, StructContent};
+use camo_derive::Camo;
+
+#[derive(Camo)]
+#[camo(strict)]
+#[serde(rename_all = "camelCase")]
+#[serde(rename(serialize = "FooOut"), default)]
+struct Foo {
+    #[serde(rename = "id", alias = "ident")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    foo: Option<u32>,
+    #[serde(flatten)]
+    bar: Bar,
+}
+
+#[derive(Camo)]
+#[camo(strict)]
+#[serde(tag = "type", content = "value")]
+enum Bar {
+    #[serde(rename = "a", rename_all = "snake_case")]
+    A { value: u32 },
+    #[serde(skip_deserializing)]
+    B,
+}
+
+fn main() {
+    let foo = Foo::camo();
+    assert_eq!(
+        foo.attributes,
+        ContainerAttributes {
+            rename_all: Some(RenameRule::CamelCase),
+            rename_serialize: Some("FooOut"),
+            default: true,
+            ..ContainerAttributes::default()
+        }
+    );
+    let fields = match foo.item {
+        Item::Struct(s) => match s.content {
+            StructContent::NamedFields(fields) => fields,
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        fields[0].attributes,
+        FieldAttributes {
+            rename: Some("id"),
+            aliases: Vec::from(["ident"]),
+            skip_serializing_if: Some("Option::is_none"),
+            ..FieldAttributes::default()
+        }
+    );
+
+    let bar = Bar::camo();
+    assert_eq!(bar.attributes.tag, Some("type"));
+    assert_eq!(bar.attributes.content, Some("value"));
+}
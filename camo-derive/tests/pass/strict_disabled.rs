@@ -0,0 +1,15 @@
+use camo::core::Camo as _;
+use camo_derive::Camo;
+
+// Without `#[camo(strict)]` or the `strict` feature,
+// attributes that are not modeled are ignored
+#[derive(Camo)]
+#[serde(deny_unknown_fields)]
+struct Foo {
+    #[serde(with = "module")]
+    foo: u32,
+}
+
+fn main() {
+    let _ = Foo::camo();
+}
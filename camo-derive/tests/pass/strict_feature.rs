@@ -0,0 +1,28 @@
+use camo::core::{Camo as _, FieldAttributes, Item, RenameRule, StructContent};
+use camo_derive::Camo;
+
+#[derive(Camo)]
+#[serde(rename_all = "camelCase")]
+struct Foo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    foo_bar: Option<u32>,
+}
+
+fn main() {
+    let foo = Foo::camo();
+    assert_eq!(foo.attributes.rename_all, Some(RenameRule::CamelCase));
+    let fields = match foo.item {
+        Item::Struct(s) => match s.content {
+            StructContent::NamedFields(fields) => fields,
+            _ => unreachable!(),
+        },
+        _ => unreachable!(),
+    };
+    assert_eq!(
+        fields[0].attributes,
+        FieldAttributes {
+            skip_serializing_if: Some("Option::is_none"),
+            ..FieldAttributes::default()
+        }
+    );
+}
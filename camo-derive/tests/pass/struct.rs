@@ -1,4 +1,4 @@
-use camo::core::{Camo as _, Container, ContainerAttributes, Item, Struct, Visibility, StructContent, NamedField, Type, PathSegment, TypePath};
+use camo::core::{Camo as _, Container, ContainerAttributes, Item, Struct, Visibility, StructContent, FieldAttributes, NamedField, Type, PathSegment, TypePath, Docs};
 use camo_derive::Camo;
 
 #[derive(Camo)]
@@ -14,7 +14,9 @@ fn main() {
     assert_eq!(
         foo,
         Container {
+            module_path: module_path!(),
             attributes: ContainerAttributes::default(),
+            docs: Docs::default(),
             item: Item::Struct(Struct {
                 visibility: Visibility::None,
                 name: "Foo",
@@ -22,6 +24,8 @@ fn main() {
                 content: StructContent::NamedFields(
                     Vec::from([
                         NamedField {
+                            attributes: FieldAttributes::default(),
+                            docs: Docs::default(),
                             name: "foo",
                             ty: Type::Path(TypePath::from([PathSegment {
                                 name: "u32",
@@ -29,6 +33,8 @@ fn main() {
                             }])),
                         },
                         NamedField {
+                            attributes: FieldAttributes::default(),
+                            docs: Docs::default(),
                             name: "bar",
                             ty: Type::Path(TypePath::from([PathSegment {
                                 name: "bool",
@@ -36,6 +42,8 @@ fn main() {
                             }])),
                         },
                         NamedField {
+                            attributes: FieldAttributes::default(),
+                            docs: Docs::default(),
                             name: "baz",
                             ty: Type::Path(TypePath::from([PathSegment {
                                 name: "char",
@@ -0,0 +1,36 @@
+use camo::core::{Camo as _, Container, ContainerAttributes, Item, Struct, Visibility, StructContent, UnnamedField, FieldAttributes, Type, PathSegment, TypePath, BuiltinType, Docs};
+use camo_derive::Camo;
+
+#[derive(Camo)]
+struct Foo(i32, String);
+
+fn main() {
+    let foo = Foo::camo();
+
+    assert_eq!(
+        foo,
+        Container {
+            module_path: module_path!(),
+            attributes: ContainerAttributes::default(),
+            docs: Docs::default(),
+            item: Item::Struct(Struct {
+                visibility: Visibility::None,
+                name: "Foo",
+                parameters: Vec::new(),
+                content: StructContent::UnnamedFields(Vec::from([
+                    UnnamedField {
+                        attributes: FieldAttributes::default(),
+                        ty: Type::Path(TypePath::from([PathSegment {
+                            name: "i32",
+                            arguments: Vec::new(),
+                        }])),
+                    },
+                    UnnamedField {
+                        attributes: FieldAttributes::default(),
+                        ty: Type::Builtin(BuiltinType::String),
+                    },
+                ])),
+            }),
+        }
+    );
+}
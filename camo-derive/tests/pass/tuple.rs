@@ -0,0 +1,51 @@
+use camo::core::{Camo as _, Container, ContainerAttributes, Item, Struct, Visibility, StructContent, FieldAttributes, NamedField, Type, TupleType, PathSegment, TypePath, Docs};
+use camo_derive::Camo;
+
+#[derive(Camo)]
+struct Foo {
+    point: (f64, f64),
+    unit: (),
+}
+
+fn main() {
+    let foo = Foo::camo();
+
+    assert_eq!(
+        foo,
+        Container {
+            module_path: module_path!(),
+            attributes: ContainerAttributes::default(),
+            docs: Docs::default(),
+            item: Item::Struct(Struct {
+                visibility: Visibility::None,
+                name: "Foo",
+                parameters: Vec::new(),
+                content: StructContent::NamedFields(
+                    Vec::from([
+                        NamedField {
+                            attributes: FieldAttributes::default(),
+                            docs: Docs::default(),
+                            name: "point",
+                            ty: Type::Tuple(TupleType(Vec::from([
+                                Type::Path(TypePath::from([PathSegment {
+                                    name: "f64",
+                                    arguments: Vec::new(),
+                                }])),
+                                Type::Path(TypePath::from([PathSegment {
+                                    name: "f64",
+                                    arguments: Vec::new(),
+                                }])),
+                            ]))),
+                        },
+                        NamedField {
+                            attributes: FieldAttributes::default(),
+                            docs: Docs::default(),
+                            name: "unit",
+                            ty: Type::Tuple(TupleType::unit()),
+                        },
+                    ]),
+                ),
+            }),
+        }
+    );
+}
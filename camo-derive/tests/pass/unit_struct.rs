@@ -0,0 +1,24 @@
+use camo::core::{Camo as _, Container, ContainerAttributes, Item, Struct, Visibility, StructContent, Docs};
+use camo_derive::Camo;
+
+#[derive(Camo)]
+struct Ping;
+
+fn main() {
+    let ping = Ping::camo();
+
+    assert_eq!(
+        ping,
+        Container {
+            module_path: module_path!(),
+            attributes: ContainerAttributes::default(),
+            docs: Docs::default(),
+            item: Item::Struct(Struct {
+                visibility: Visibility::None,
+                name: "Ping",
+                parameters: Vec::new(),
+                content: StructContent::Unit,
+            }),
+        }
+    );
+}
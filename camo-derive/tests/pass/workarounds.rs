@@ -0,0 +1,26 @@
+use camo::core::{Camo as _, Item, StructContent, Type, Visibility};
+use camo_derive::Camo;
+
+#[derive(Camo)]
+#[camo(export = true)]
+pub(crate) struct Foo {
+    #[camo(skip)]
+    callback: fn(i32) -> i32,
+    value: u32,
+}
+
+fn main() {
+    let foo = Foo::camo();
+    assert_eq!(foo.attributes.export, Some(true));
+    let s = match foo.item {
+        Item::Struct(s) => s,
+        _ => unreachable!(),
+    };
+    assert_eq!(s.visibility, Visibility::None);
+    let fields = match s.content {
+        StructContent::NamedFields(fields) => fields,
+        _ => unreachable!(),
+    };
+    assert!(fields[0].attributes.camo_skip);
+    assert_eq!(fields[0].ty, Type::Opaque);
+}
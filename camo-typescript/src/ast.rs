@@ -1,6 +1,8 @@
 use camo_core as camo;
 use std::{convert::TryFrom, fmt};
 
+use crate::options::{EnumStyle, OptionStyle, Options};
+
 /// A top-level type definition.
 #[derive(Clone, Debug, PartialEq)]
 pub enum Definition {
@@ -8,6 +10,11 @@ pub enum Definition {
     Interface(Interface),
     /// A type definition.
     Alias(TypeAlias),
+    /// An `enum` definition.
+    Enum(Enum),
+    /// A type definition for a union of literal types,
+    /// along with a constant that lists the literal values.
+    Values(Values),
 }
 
 impl Definition {
@@ -16,6 +23,8 @@ impl Definition {
         match self {
             Definition::Interface(i) => &i.name,
             Definition::Alias(a) => &a.name,
+            Definition::Enum(e) => &e.name,
+            Definition::Values(v) => &v.alias.name,
         }
     }
 
@@ -24,6 +33,8 @@ impl Definition {
         match self {
             Definition::Interface(i) => i.export,
             Definition::Alias(a) => a.export,
+            Definition::Enum(e) => e.export,
+            Definition::Values(v) => v.alias.export,
         }
     }
 }
@@ -40,6 +51,18 @@ impl From<TypeAlias> for Definition {
     }
 }
 
+impl From<Enum> for Definition {
+    fn from(value: Enum) -> Self {
+        Definition::Enum(value)
+    }
+}
+
+impl From<Values> for Definition {
+    fn from(value: Values) -> Self {
+        Definition::Values(value)
+    }
+}
+
 #[derive(Clone, Copy)]
 struct Renamer(Option<camo::RenameRule>);
 
@@ -59,6 +82,13 @@ impl Renamer {
         }
     }
 
+    fn rename_variant(&self, variant: &camo::Variant, direction: camo::Direction) -> String {
+        match variant.attributes.rename_for(direction) {
+            Some(rename) => rename.to_string(),
+            None => self.rename_type(variant.name),
+        }
+    }
+
     fn rename_type(&self, name: &str) -> String {
         let Self(rule) = self;
         match rule {
@@ -79,6 +109,25 @@ impl Renamer {
     }
 }
 
+/// The state that is threaded through the conversion of a container.
+#[derive(Clone, Copy)]
+struct Context<'a> {
+    direction: camo::Direction,
+    options: &'a Options,
+    /// The identity of the container in Rust, which keys its field overrides.
+    container: camo::TypeIdentity,
+    /// Whether the definition is marked with `export`.
+    export: bool,
+    /// The documentation of the definition.
+    docs: &'a [String],
+}
+
+impl Context<'_> {
+    fn ty(&self, ty: camo::Type) -> Type {
+        Type::from_type(ty, self.options)
+    }
+}
+
 fn snake_to_non_snake_case(capitalize_first: bool, field: &str) -> String {
     let mut result = String::new();
     let mut capitalize = capitalize_first;
@@ -107,70 +156,290 @@ fn pascal_to_separated_case(separator: char, name: &str) -> String {
 }
 
 impl From<camo::Container> for Definition {
+    /// Converts the container into a TypeScript definition of the values
+    /// that `serde` produces when serializing.
+    ///
+    /// See [`Definition::from_container`].
     fn from(container: camo::Container) -> Self {
-        let rename = Renamer(container.attributes.rename);
-        let rename_all = Renamer(container.attributes.rename_all);
+        Definition::from_container(container, camo::Direction::Serialize)
+    }
+}
+
+/// Converts a container into a TypeScript definition with the given options.
+pub trait IntoDefinition {
+    /// Converts the container into a TypeScript definition of the values
+    /// that `serde` produces when serializing, using the given options.
+    ///
+    /// See [`Definition::from_container_with`].
+    fn into_definition_with(self, options: &Options) -> Definition;
+}
+
+impl IntoDefinition for camo::Container {
+    fn into_definition_with(self, options: &Options) -> Definition {
+        Definition::from_container_with(self, camo::Direction::Serialize, options)
+    }
+}
+
+impl From<camo::Instance> for Definition {
+    /// Converts the instantiated container into a TypeScript definition
+    /// named after the instance.
+    fn from(instance: camo::Instance) -> Self {
+        Definition::from(instance.container).renamed(instance.name)
+    }
+}
+
+impl IntoDefinition for camo::Instance {
+    fn into_definition_with(self, options: &Options) -> Definition {
+        self.container
+            .into_definition_with(options)
+            .renamed(self.name)
+    }
+}
+
+impl Definition {
+    /// Converts the container into a TypeScript definition of the values
+    /// that pass through `serde` in the given direction.
+    ///
+    /// When serializing, a container with `#[serde(into = "...")]` becomes
+    /// an alias for that type, while `from` or `try_from` are used when
+    /// deserializing. Likewise, fields are left out, renamed or made optional
+    /// according to the attributes that apply in the given direction.
+    ///
+    /// Only the container itself is converted for the given direction:
+    /// the types that it refers to keep their names, so a field of type `Bar`
+    /// refers to `Bar` rather than to e.g. `BarInput`. The definitions
+    /// are therefore only exact if the types that the container refers to
+    /// look the same in both directions.
+    ///
+    /// Since both definitions have the same name, one of them will usually
+    /// be renamed (see [`Definition::renamed`]):
+    ///
+    /// ```
+    /// use camo_core::{Camo, Container, Direction};
+    /// use camo_typescript::Definition;
+    ///
+    /// fn definitions<T: Camo>() -> (Definition, Definition) {
+    ///     let container = T::camo();
+    ///     let name = Definition::from(container.clone()).name().to_string();
+    ///     (
+    ///         Definition::from_container(container.clone(), Direction::Deserialize)
+    ///             .renamed(format!("{}Input", name)),
+    ///         Definition::from_container(container, Direction::Serialize)
+    ///             .renamed(format!("{}Output", name)),
+    ///     )
+    /// }
+    /// ```
+    pub fn from_container(container: camo::Container, direction: camo::Direction) -> Self {
+        Definition::from_container_with(container, direction, &Options::default())
+    }
+
+    /// Converts the container into a TypeScript definition of the values
+    /// that pass through `serde` in the given direction, using the given options.
+    ///
+    /// See [`Definition::from_container`] and [`Options`].
+    pub fn from_container_with(
+        container: camo::Container,
+        direction: camo::Direction,
+        options: &Options,
+    ) -> Self {
+        let visibility = match &container.item {
+            camo::Item::Struct(s) => &s.visibility,
+            camo::Item::Enum(e) => &e.visibility,
+        };
+        let docs = jsdoc(&container.docs);
+        let cx = Context {
+            direction,
+            options,
+            container: container.identity(),
+            export: container
+                .attributes
+                .export
+                .unwrap_or_else(|| options.is_exported(visibility)),
+            docs: &docs,
+        };
+        let rename = container.attributes.rename_for(direction);
+        let rename_all = Renamer(container.attributes.rename_all_for(direction));
         let tag_rule = container.attributes.tag;
         let content_rule = container.attributes.content;
 
+        // Types that are converted from or into another type,
+        // or serialized as their only field, are described by that type
+        if let Some(ty) = container.attributes.proxy_for(direction) {
+            let ty = cx.ty(ty.clone());
+            return Definition::Alias(TypeAlias::proxy(rename, container.item, ty, cx));
+        }
+        if container.attributes.transparent {
+            if let Some(ty) = transparent_field(&container.item, direction) {
+                return Definition::Alias(TypeAlias::proxy(rename, container.item, cx.ty(ty), cx));
+            }
+        }
+
         match container.item {
             camo::Item::Struct(s) => match s.content {
-                camo::StructContent::NamedFields(fields) => Definition::Interface(Interface {
-                    export: s.visibility.is_pub(),
-                    name: rename.rename_type(s.name),
-                    parameters: s
-                        .parameters
-                        .into_iter()
-                        .filter_map(|parameter| match parameter {
-                            // Lifetimes are ignored
-                            camo::GenericParameter::Lifetime(_) => None,
-                            camo::GenericParameter::Type(ty) => Some(ty),
-                        })
-                        .collect(),
-                    fields: fields
-                        .into_iter()
-                        .map(|field| Field {
-                            name: rename_all.rename_field(field.name),
-                            ty: Type::from(field.ty),
-                            optional: false,
-                        })
-                        .collect(),
-                }),
-                camo::StructContent::UnnamedField(field) => {
-                    Definition::Alias(TypeAlias {
-                        export: s.visibility.is_pub(),
-                        name: rename.rename_type(s.name),
-                        parameters: s
-                            .parameters
-                            .into_iter()
-                            .filter_map(|parameter| match parameter {
-                                // Lifetimes are ignored
-                                camo::GenericParameter::Lifetime(_) => None,
-                                camo::GenericParameter::Type(ty) => Some(ty),
+                camo::StructContent::NamedFields(mut fields) => {
+                    if container.attributes.default {
+                        // Every field falls back to the default value of the struct
+                        for field in &mut fields {
+                            field.attributes.default = true;
+                        }
+                    }
+                    let export = cx.export;
+                    let name = rename.unwrap_or(s.name).to_string();
+                    let parameters = TypeParameter::from_parameters(s.parameters, cx);
+                    match Type::from_named_fields(rename_all, fields, cx) {
+                        Type::Object(object) if cx.options.uses_interfaces() => {
+                            Definition::Interface(Interface {
+                                export,
+                                docs: cx.docs.to_vec(),
+                                name,
+                                parameters,
+                                fields: object.fields,
                             })
-                            .collect(),
-                        ty: Type::from(field.ty),
-                    })
+                        }
+                        // Flattened fields cannot be expressed in an interface,
+                        // and the options may prefer type aliases
+                        ty => Definition::Alias(TypeAlias {
+                            export,
+                            docs: cx.docs.to_vec(),
+                            name,
+                            parameters,
+                            ty,
+                        }),
+                    }
                 }
+                camo::StructContent::UnnamedFields(fields) => Definition::Alias(TypeAlias {
+                    export: cx.export,
+                    docs: cx.docs.to_vec(),
+                    name: rename.unwrap_or(s.name).to_string(),
+                    parameters: TypeParameter::from_parameters(s.parameters, cx),
+                    ty: Type::from_unnamed_fields(fields, cx),
+                }),
+                // Unit structs are serialized as `null`
+                camo::StructContent::Unit => Definition::Alias(TypeAlias {
+                    export: cx.export,
+                    docs: cx.docs.to_vec(),
+                    name: rename.unwrap_or(s.name).to_string(),
+                    parameters: TypeParameter::from_parameters(s.parameters, cx),
+                    ty: Type::Builtin(BuiltinType::Null),
+                }),
             },
-            camo::Item::Enum(ty) => Definition::Alias(if let Some(tag) = tag_rule {
-                if let Some(content) = content_rule {
-                    TypeAlias::adjacently_tagged(rename, rename_all, tag, content, ty)
+            camo::Item::Enum(ty) => {
+                let name = rename.unwrap_or(ty.name).to_string();
+                // Only enums that are serialized as plain strings or numbers
+                // can be described by their values
+                let repr = container.attributes.repr;
+                let members = if cx.options.enums() != EnumStyle::Union
+                    && (repr || !container.attributes.untagged && tag_rule.is_none())
+                {
+                    EnumMember::from_unit_variants(rename_all, &ty, repr, cx)
+                } else {
+                    None
+                };
+                let alias = if repr {
+                    TypeAlias::repr(name, ty, cx)
+                } else if container.attributes.untagged {
+                    TypeAlias::untagged(name, ty, cx)
+                } else if let Some(tag) = tag_rule {
+                    if let Some(content) = content_rule {
+                        TypeAlias::adjacently_tagged(name, rename_all, tag, content, ty, cx)
+                    } else {
+                        TypeAlias::internally_tagged(name, rename_all, tag, ty, cx)
+                    }
                 } else {
-                    TypeAlias::internally_tagged(rename, rename_all, tag, ty)
+                    TypeAlias::externally_tagged(name, rename_all, ty, cx)
+                };
+                match members {
+                    Some(members) => match cx.options.enums() {
+                        EnumStyle::Enum | EnumStyle::ConstEnum => Definition::Enum(Enum {
+                            export: alias.export,
+                            docs: alias.docs,
+                            is_const: cx.options.enums() == EnumStyle::ConstEnum,
+                            name: alias.name,
+                            members,
+                        }),
+                        EnumStyle::Union | EnumStyle::UnionWithValues => {
+                            Definition::Values(Values {
+                                constant: Const {
+                                    export: alias.export,
+                                    name: Const::values_name(&alias.name),
+                                    values: members
+                                        .into_iter()
+                                        .map(|member| member.value)
+                                        .collect(),
+                                },
+                                alias,
+                            })
+                        }
+                    },
+                    None => Definition::Alias(alias),
                 }
-            } else {
-                TypeAlias::externally_tagged(rename, rename_all, ty)
+            }
+        }
+    }
+
+    /// Renders the type definition with the indentation and quotes
+    /// given by the options, where `to_string` uses the defaults.
+    pub fn display_with<'a>(&'a self, options: &'a Options) -> impl fmt::Display + 'a {
+        Rendered(self, options)
+    }
+
+    /// Changes the name of the type definition.
+    ///
+    /// References to the definition from other definitions are not changed.
+    pub fn renamed<S: Into<String>>(self, name: S) -> Self {
+        match self {
+            Definition::Interface(i) => Definition::Interface(Interface {
+                name: name.into(),
+                ..i
+            }),
+            Definition::Alias(a) => Definition::Alias(TypeAlias {
+                name: name.into(),
+                ..a
             }),
+            Definition::Enum(e) => Definition::Enum(Enum {
+                name: name.into(),
+                ..e
+            }),
+            Definition::Values(v) => {
+                let name = name.into();
+                Definition::Values(Values {
+                    constant: Const {
+                        name: Const::values_name(&name),
+                        ..v.constant
+                    },
+                    alias: TypeAlias { name, ..v.alias },
+                })
+            }
         }
     }
 }
 
-impl fmt::Display for Definition {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+/// The type of the single field of a `#[serde(transparent)]` struct,
+/// ignoring skipped fields.
+fn transparent_field(item: &camo::Item, direction: camo::Direction) -> Option<camo::Type> {
+    match item {
+        camo::Item::Struct(s) => match &s.content {
+            camo::StructContent::NamedFields(fields) => fields
+                .iter()
+                .find(|field| !field.attributes.is_skipped(direction))
+                .map(|field| field.ty.clone()),
+            camo::StructContent::UnnamedFields(fields) => fields
+                .iter()
+                .find(|field| !field.attributes.is_skipped(direction))
+                .map(|field| field.ty.clone()),
+            camo::StructContent::Unit => None,
+        },
+        camo::Item::Enum(_) => None,
+    }
+}
+
+impl Render for Definition {
+    fn render(&self, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result {
         match self {
-            Definition::Interface(ty) => write!(f, "{}", ty),
-            Definition::Alias(ty) => write!(f, "{}", ty),
+            Definition::Interface(ty) => ty.render(f, options),
+            Definition::Alias(ty) => ty.render(f, options),
+            Definition::Enum(ty) => ty.render(f, options),
+            Definition::Values(ty) => ty.render(f, options),
         }
     }
 }
@@ -190,30 +459,30 @@ impl fmt::Display for Definition {
 pub struct Interface {
     /// Whether the interface is marked with `export`.
     pub export: bool,
+    /// The lines of the documentation comment of the interface.
+    pub docs: Vec<String>,
     /// The name of the interface.
     pub name: String,
     /// The generic parameters of the interface.
-    pub parameters: Vec<&'static str>,
+    pub parameters: Vec<TypeParameter>,
     /// The fields of the interface.
     pub fields: Vec<Field>,
 }
 
-impl fmt::Display for Interface {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl Render for Interface {
+    fn render(&self, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result {
+        render_docs(&self.docs, "", f)?;
         if self.export {
             write!(f, "export ")?;
         }
         write!(f, "interface {}", self.name)?;
-        if !self.parameters.is_empty() {
-            write!(f, "<")?;
-            for parameter in &self.parameters {
-                write!(f, "{}", parameter)?;
-            }
-            write!(f, ">")?;
-        }
+        render_parameters(&self.parameters, f, options)?;
         writeln!(f, " {{")?;
         for field in &self.fields {
-            writeln!(f, "\t{}", field)?;
+            render_docs(&field.docs, options.indentation(), f)?;
+            write!(f, "{}", options.indentation())?;
+            field.render_declaration(f, options)?;
+            writeln!(f)?;
         }
         writeln!(f, "}}")
     }
@@ -228,25 +497,163 @@ pub struct Field {
     pub ty: Type,
     /// Whether the field is optional, e.g. `optional?: boolean`.
     pub optional: bool,
+    /// Whether the field is marked with `readonly`.
+    pub readonly: bool,
+    /// The lines of the documentation comment of the field.
+    ///
+    /// These are left out when the field is part of an object type,
+    /// which is written on a single line.
+    pub docs: Vec<String>,
 }
 
-impl fmt::Display for Field {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let name = &self.name;
-        let ty = &self.ty;
+impl Field {
+    fn from_named(rename_all: Renamer, field: camo::NamedField, cx: Context<'_>) -> Self {
+        let name = match field.attributes.rename_for(cx.direction) {
+            Some(rename) => rename.to_string(),
+            None => rename_all.rename_field(field.name),
+        };
+        let optional = field.attributes.is_optional(cx.direction);
+        let overridden = cx
+            .options
+            .field_type(cx.container, field.name)
+            .or_else(|| field.attributes.typescript.map(Type::from));
+        let (optional, ty) = match (overridden, field.ty) {
+            (Some(ty), _) => (optional, ty),
+            (None, camo::Type::Optional(ty)) if cx.options.optional() == OptionStyle::Optional => {
+                (true, cx.ty(*ty.0))
+            }
+            (None, ty) => (optional, cx.ty(ty)),
+        };
+        Self {
+            name,
+            ty,
+            optional,
+            readonly: cx.options.is_readonly(),
+            docs: jsdoc(&field.docs),
+        }
+    }
 
+    /// Writes the field without its documentation.
+    fn render_declaration(&self, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result {
+        if self.readonly {
+            write!(f, "readonly ")?;
+        }
         if is_valid_identifier(self.name.as_str()) {
-            if self.optional {
-                write!(f, "{name}?: {ty};")
+            write!(f, "{}", self.name)?;
+        } else {
+            render_string(&self.name, f, options)?;
+        }
+        if self.optional {
+            write!(f, "?")?;
+        }
+        write!(f, ": {};", Rendered(&self.ty, options))
+    }
+}
+
+impl Render for Field {
+    fn render(&self, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result {
+        render_docs(&self.docs, "", f)?;
+        self.render_declaration(f, options)
+    }
+}
+
+/// Converts Rust documentation into the lines of a JSDoc comment.
+///
+/// Code blocks without a language are Rust code blocks in Rust documentation,
+/// so they are marked as such, and the lines that `rustdoc` hides
+/// (those starting with `# `) are left out. Deprecated items are
+/// marked with `@deprecated`.
+fn jsdoc(docs: &camo::Docs) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut rust_code = false;
+    let mut in_code = false;
+    for line in &docs.lines {
+        let trimmed = line.trim_start();
+        if let Some(info) = trimmed.strip_prefix("```") {
+            if in_code {
+                in_code = false;
+                rust_code = false;
             } else {
-                write!(f, "{name}: {ty};")
+                in_code = true;
+                rust_code = is_rust_code_block(info);
+                if rust_code {
+                    let indentation = &line[..line.len() - trimmed.len()];
+                    lines.push(format!("{}```rust", indentation));
+                    continue;
+                }
+            }
+        } else if rust_code {
+            if trimmed == "#" || trimmed.starts_with("# ") {
+                continue;
+            }
+            if let Some(rest) = trimmed.strip_prefix("##") {
+                let indentation = &line[..line.len() - trimmed.len()];
+                lines.push(format!("{}#{}", indentation, rest).replace("*/", "*\\/"));
+                continue;
             }
-        } else if self.optional {
-            write!(f, r#"'{name}'?: {ty};"#)
-        } else {
-            write!(f, r#"'{name}': {ty};"#)
         }
+        lines.push(line.replace("*/", "*\\/"));
     }
+    if let Some(deprecation) = &docs.deprecated {
+        if !lines.is_empty() {
+            lines.push(String::new());
+        }
+        let mut line = String::from("@deprecated");
+        if let Some(since) = deprecation.since {
+            line.push_str(&format!(" Since {}.", since));
+        }
+        if let Some(note) = deprecation.note {
+            line.push_str(&format!(" {}", note));
+        }
+        lines.push(line.replace("*/", "*\\/"));
+    }
+    lines
+}
+
+/// Whether a code block with the given info string (e.g. `rust,no_run`)
+/// contains Rust code, like `rustdoc` decides it.
+fn is_rust_code_block(info: &str) -> bool {
+    info.split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|attribute| !attribute.is_empty())
+        .all(|attribute| {
+            matches!(
+                attribute,
+                "rust" | "ignore" | "should_panic" | "no_run" | "compile_fail" | "test_harness"
+            ) || attribute.starts_with("edition")
+        })
+}
+
+/// Writes the lines of a JSDoc comment, each starting with `indentation`.
+fn render_docs(docs: &[String], indentation: &str, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match docs {
+        [] => Ok(()),
+        [line] => writeln!(f, "{}/** {} */", indentation, line),
+        lines => {
+            writeln!(f, "{}/**", indentation)?;
+            for line in lines {
+                if line.is_empty() {
+                    writeln!(f, "{} *", indentation)?;
+                } else {
+                    writeln!(f, "{} * {}", indentation, line)?;
+                }
+            }
+            writeln!(f, "{} */", indentation)
+        }
+    }
+}
+
+/// Writes a string literal with the configured quotes,
+/// escaping the quote character and backslashes.
+fn render_string(string: &str, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result {
+    let quote = options.quote_char();
+    write!(f, "{}", quote)?;
+    for ch in string.chars() {
+        if ch == quote || ch == '\\' {
+            write!(f, "\\")?;
+        }
+        write!(f, "{}", ch)?;
+    }
+    write!(f, "{}", quote)
 }
 
 fn is_valid_identifier(string: &str) -> bool {
@@ -259,6 +666,75 @@ fn is_valid_identifier(string: &str) -> bool {
     chars.all(|c| c.is_alphanumeric() || c == '_')
 }
 
+/// A generic type parameter, e.g. `T` or `T = unknown`.
+///
+/// Trait bounds and const parameters have no meaning in TypeScript,
+/// so they are left out.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TypeParameter {
+    /// The name of the parameter.
+    pub name: String,
+    /// The default type of the parameter, if any.
+    pub default: Option<Type>,
+}
+
+impl TypeParameter {
+    fn from_parameters(parameters: Vec<camo::GenericParameter>, cx: Context<'_>) -> Vec<Self> {
+        parameters
+            .into_iter()
+            .filter_map(|parameter| match parameter {
+                camo::GenericParameter::Type(parameter) => Some(TypeParameter {
+                    name: parameter.name.to_string(),
+                    default: parameter.default.map(|ty| cx.ty(ty)),
+                }),
+                // Lifetimes are ignored
+                camo::GenericParameter::Lifetime(_) => None,
+                // TypeScript has no const parameters, and array lengths that
+                // refer to one are not known, so such arrays have no fixed length
+                camo::GenericParameter::Const(_) => None,
+            })
+            .collect()
+    }
+}
+
+impl From<&str> for TypeParameter {
+    fn from(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            default: None,
+        }
+    }
+}
+
+impl Render for TypeParameter {
+    fn render(&self, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result {
+        write!(f, "{}", self.name)?;
+        if let Some(default) = &self.default {
+            write!(f, " = {}", Rendered(default, options))?;
+        }
+        Ok(())
+    }
+}
+
+fn render_parameters(
+    parameters: &[TypeParameter],
+    f: &mut fmt::Formatter<'_>,
+    options: &Options,
+) -> fmt::Result {
+    if !parameters.is_empty() {
+        write!(f, "<")?;
+        let mut iter = parameters.iter();
+        if let Some(parameter) = iter.next() {
+            parameter.render(f, options)?;
+        }
+        for parameter in iter {
+            write!(f, ", {}", Rendered(parameter, options))?;
+        }
+        write!(f, ">")?;
+    }
+    Ok(())
+}
+
 /// A top-level `type` definition.
 /// Example:
 ///
@@ -271,10 +747,12 @@ fn is_valid_identifier(string: &str) -> bool {
 pub struct TypeAlias {
     /// Whether the type is marked with `export`.
     pub export: bool,
+    /// The lines of the documentation comment of the type definition.
+    pub docs: Vec<String>,
     /// The name of the type definition.
     pub name: String,
     /// The generic parameters of the type definition.
-    pub parameters: Vec<&'static str>,
+    pub parameters: Vec<TypeParameter>,
     /// The content of the type definition.
     pub ty: Type,
 }
@@ -284,6 +762,7 @@ impl TypeAlias {
     pub fn alias<T: Into<Type>>(name: &str, ty: T) -> Self {
         Self {
             export: false,
+            docs: Vec::new(),
             name: String::from(name),
             parameters: Vec::new(),
             ty: ty.into(),
@@ -298,92 +777,292 @@ impl TypeAlias {
         }
     }
 
-    fn externally_tagged(rename: Renamer, rename_all: Renamer, ty: camo::Enum) -> Self {
+    fn proxy(rename: Option<&'static str>, item: camo::Item, ty: Type, cx: Context<'_>) -> Self {
+        let (name, parameters) = match item {
+            camo::Item::Struct(s) => (s.name, s.parameters),
+            camo::Item::Enum(e) => (e.name, e.parameters),
+        };
         Self {
-            export: ty.visibility.is_pub(),
-            name: rename.rename_type(ty.name),
-            parameters: ty
-                .parameters
-                .into_iter()
-                .filter_map(|parameter| match parameter {
-                    // Lifetimes are ignored
-                    camo::GenericParameter::Lifetime(_) => None,
-                    camo::GenericParameter::Type(ty) => Some(ty),
-                })
-                .collect(),
-            ty: Type::Union(UnionType::externally_tagged(rename_all, ty.variants)),
+            export: cx.export,
+            docs: cx.docs.to_vec(),
+            name: rename.unwrap_or(name).to_string(),
+            parameters: TypeParameter::from_parameters(parameters, cx),
+            ty,
+        }
+    }
+
+    fn repr(name: String, ty: camo::Enum, cx: Context<'_>) -> Self {
+        let discriminants: Vec<_> = ty
+            .discriminants()
+            .into_iter()
+            .zip(&ty.variants)
+            .filter(|(_, variant)| !variant.attributes.is_skipped(cx.direction))
+            .map(|(value, _)| value)
+            .collect();
+        Self {
+            export: cx.export,
+            docs: cx.docs.to_vec(),
+            name,
+            parameters: TypeParameter::from_parameters(ty.parameters, cx),
+            ty: Type::Union(UnionType {
+                variants: discriminants
+                    .into_iter()
+                    .map(|value| Variant(Type::Literal(LiteralType::Number(value))))
+                    .collect(),
+            }),
+        }
+    }
+
+    fn untagged(name: String, ty: camo::Enum, cx: Context<'_>) -> Self {
+        Self {
+            export: cx.export,
+            docs: cx.docs.to_vec(),
+            name,
+            parameters: TypeParameter::from_parameters(ty.parameters, cx),
+            ty: Type::Union(UnionType::untagged(ty.variants, cx)),
+        }
+    }
+
+    fn externally_tagged(
+        name: String,
+        rename_all: Renamer,
+        ty: camo::Enum,
+        cx: Context<'_>,
+    ) -> Self {
+        Self {
+            export: cx.export,
+            docs: cx.docs.to_vec(),
+            name,
+            parameters: TypeParameter::from_parameters(ty.parameters, cx),
+            ty: Type::Union(UnionType::externally_tagged(rename_all, ty.variants, cx)),
         }
     }
 
     fn adjacently_tagged(
-        rename: Renamer,
+        name: String,
         rename_all: Renamer,
         tag: &'static str,
         content: &'static str,
         ty: camo::Enum,
+        cx: Context<'_>,
     ) -> Self {
         Self {
-            export: ty.visibility.is_pub(),
-            name: rename.rename_type(ty.name),
-            parameters: ty
-                .parameters
-                .into_iter()
-                .filter_map(|parameter| match parameter {
-                    // Lifetimes are ignored
-                    camo::GenericParameter::Lifetime(_) => None,
-                    camo::GenericParameter::Type(ty) => Some(ty),
-                })
-                .collect(),
+            export: cx.export,
+            docs: cx.docs.to_vec(),
+            name,
+            parameters: TypeParameter::from_parameters(ty.parameters, cx),
             ty: Type::Union(UnionType::adjacently_tagged(
                 rename_all,
                 tag,
                 content,
                 ty.variants,
+                cx,
             )),
         }
     }
 
     fn internally_tagged(
-        rename: Renamer,
+        name: String,
         rename_all: Renamer,
         tag: &'static str,
         ty: camo::Enum,
+        cx: Context<'_>,
     ) -> Self {
         Self {
-            export: ty.visibility.is_pub(),
-            name: rename.rename_field(ty.name),
-            parameters: ty
-                .parameters
-                .into_iter()
-                .filter_map(|parameter| match parameter {
-                    // Lifetimes are ignored
-                    camo::GenericParameter::Lifetime(_) => None,
-                    camo::GenericParameter::Type(ty) => Some(ty),
-                })
-                .collect(),
-            ty: Type::Union(UnionType::internally_tagged(rename_all, tag, ty.variants)),
+            export: cx.export,
+            docs: cx.docs.to_vec(),
+            name,
+            parameters: TypeParameter::from_parameters(ty.parameters, cx),
+            ty: Type::Union(UnionType::internally_tagged(
+                rename_all,
+                tag,
+                ty.variants,
+                cx,
+            )),
         }
     }
 }
 
-impl fmt::Display for TypeAlias {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl Render for TypeAlias {
+    fn render(&self, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result {
+        render_docs(&self.docs, "", f)?;
         if self.export {
             write!(f, "export ")?;
         }
         write!(f, "type {}", self.name)?;
-        if !self.parameters.is_empty() {
-            write!(f, "<")?;
-            for parameter in &self.parameters {
-                write!(f, "{}", parameter)?;
-            }
-            write!(f, ">")?;
-        }
+        render_parameters(&self.parameters, f, options)?;
         if self.ty.is_union() {
-            writeln!(f, " ={};", self.ty)
+            writeln!(f, " ={};", Rendered(&self.ty, options))
         } else {
-            writeln!(f, " = {};", self.ty)
+            writeln!(f, " = {};", Rendered(&self.ty, options))
+        }
+    }
+}
+
+/// A top-level `enum` definition.
+///
+/// Example:
+///
+/// ```ts
+/// enum Direction {
+///     Up = "Up",
+///     Down = "Down",
+/// }
+/// ```
+///
+/// See: <https://www.typescriptlang.org/docs/handbook/enums.html>
+#[derive(Clone, Debug, PartialEq)]
+pub struct Enum {
+    /// Whether the enum is marked with `export`.
+    pub export: bool,
+    /// The lines of the documentation comment of the enum.
+    pub docs: Vec<String>,
+    /// Whether the enum is a `const enum`, which is inlined where it is used.
+    pub is_const: bool,
+    /// The name of the enum.
+    pub name: String,
+    /// The members of the enum.
+    pub members: Vec<EnumMember>,
+}
+
+impl Render for Enum {
+    fn render(&self, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result {
+        render_docs(&self.docs, "", f)?;
+        if self.export {
+            write!(f, "export ")?;
+        }
+        if self.is_const {
+            write!(f, "const ")?;
+        }
+        writeln!(f, "enum {} {{", self.name)?;
+        for member in &self.members {
+            writeln!(
+                f,
+                "{}{} = {},",
+                options.indentation(),
+                member.name,
+                Rendered(&member.value, options)
+            )?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+/// A member of an enum, e.g. `Up = "Up"`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnumMember {
+    /// The name of the member.
+    pub name: String,
+    /// The value of the member.
+    pub value: LiteralType,
+}
+
+impl EnumMember {
+    /// The members of an enum whose variants are all unit variants,
+    /// named as in Rust and valued as they are serialized.
+    ///
+    /// Returns `None` if any variant that is not skipped has fields
+    /// or is untagged.
+    fn from_unit_variants(
+        rename_all: Renamer,
+        ty: &camo::Enum,
+        repr: bool,
+        cx: Context<'_>,
+    ) -> Option<Vec<Self>> {
+        let mut members = Vec::new();
+        for (variant, discriminant) in ty.variants.iter().zip(ty.discriminants()) {
+            if variant.attributes.is_skipped(cx.direction) {
+                continue;
+            }
+            if variant.attributes.untagged || variant.content != camo::VariantContent::Unit {
+                return None;
+            }
+            members.push(EnumMember {
+                name: variant.name.to_string(),
+                value: if repr {
+                    LiteralType::Number(discriminant)
+                } else {
+                    LiteralType::String(rename_all.rename_variant(variant, cx.direction))
+                },
+            });
         }
+        Some(members)
+    }
+}
+
+/// A top-level type definition for a union of literal types,
+/// along with a constant that lists the literal values
+/// (e.g. for exhaustive iteration).
+///
+/// Example:
+///
+/// ```ts
+/// type Direction =
+///     | "Up"
+///     | "Down";
+/// const ALL_DIRECTIONS = ["Up", "Down"] as const;
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Values {
+    /// The type definition of the union.
+    pub alias: TypeAlias,
+    /// The constant that lists the values of the union.
+    pub constant: Const,
+}
+
+impl Render for Values {
+    fn render(&self, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result {
+        self.alias.render(f, options)?;
+        self.constant.render(f, options)
+    }
+}
+
+/// A top-level constant array of literal values.
+///
+/// Example:
+///
+/// ```ts
+/// const ALL_DIRECTIONS = ["Up", "Down"] as const;
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Const {
+    /// Whether the constant is marked with `export`.
+    pub export: bool,
+    /// The name of the constant.
+    pub name: String,
+    /// The values in the array.
+    pub values: Vec<LiteralType>,
+}
+
+impl Const {
+    /// The name of the constant that lists the values of the given type,
+    /// e.g. `ALL_BINDING_TYPES` for `BindingType`.
+    pub fn values_name(name: &str) -> String {
+        let name = pascal_to_separated_case('_', name).to_uppercase();
+        let plural =
+            if name.ends_with(['S', 'X', 'Z']) || name.ends_with("CH") || name.ends_with("SH") {
+                format!("{}ES", name)
+            } else {
+                format!("{}S", name)
+            };
+        format!("ALL_{}", plural)
+    }
+}
+
+impl Render for Const {
+    fn render(&self, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result {
+        if self.export {
+            write!(f, "export ")?;
+        }
+        write!(f, "const {} = [", self.name)?;
+        let mut iter = self.values.iter();
+        if let Some(value) = iter.next() {
+            value.render(f, options)?;
+        }
+        for value in iter {
+            write!(f, ", {}", Rendered(value, options))?;
+        }
+        writeln!(f, "] as const;")
     }
 }
 
@@ -405,11 +1084,32 @@ pub struct UnionType {
 }
 
 impl UnionType {
-    fn externally_tagged(rename_all: Renamer, variants: Vec<camo::Variant>) -> Self {
+    fn untagged(variants: Vec<camo::Variant>, cx: Context<'_>) -> Self {
         Self {
             variants: variants
                 .into_iter()
-                .map(|variant| Variant::externally_tagged(rename_all, variant))
+                .filter(|variant| !variant.attributes.is_skipped(cx.direction))
+                .map(|variant| Variant::untagged(variant, cx))
+                .collect(),
+        }
+    }
+
+    fn externally_tagged(
+        rename_all: Renamer,
+        variants: Vec<camo::Variant>,
+        cx: Context<'_>,
+    ) -> Self {
+        Self {
+            variants: variants
+                .into_iter()
+                .filter(|variant| !variant.attributes.is_skipped(cx.direction))
+                .map(|variant| {
+                    if variant.attributes.untagged {
+                        Variant::untagged(variant, cx)
+                    } else {
+                        Variant::externally_tagged(rename_all, variant, cx)
+                    }
+                })
                 .collect(),
         }
     }
@@ -419,11 +1119,19 @@ impl UnionType {
         tag: &'static str,
         content: &'static str,
         variants: Vec<camo::Variant>,
+        cx: Context<'_>,
     ) -> Self {
         Self {
             variants: variants
                 .into_iter()
-                .map(|variant| Variant::adjacently_tagged(rename_all, tag, content, variant))
+                .filter(|variant| !variant.attributes.is_skipped(cx.direction))
+                .map(|variant| {
+                    if variant.attributes.untagged {
+                        Variant::untagged(variant, cx)
+                    } else {
+                        Variant::adjacently_tagged(rename_all, tag, content, variant, cx)
+                    }
+                })
                 .collect(),
         }
     }
@@ -432,20 +1140,47 @@ impl UnionType {
         rename_all: Renamer,
         tag: &'static str,
         variants: Vec<camo::Variant>,
+        cx: Context<'_>,
     ) -> Self {
         Self {
             variants: variants
                 .into_iter()
-                .map(|variant| Variant::internally_tagged(rename_all, tag, variant))
+                .filter(|variant| !variant.attributes.is_skipped(cx.direction))
+                .map(|variant| {
+                    if variant.attributes.untagged {
+                        Variant::untagged(variant, cx)
+                    } else {
+                        Variant::internally_tagged(rename_all, tag, variant, cx)
+                    }
+                })
                 .collect(),
         }
     }
 }
 
-impl fmt::Display for UnionType {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl UnionType {
+    /// Whether the union has no variants (not even in nested unions),
+    /// as for an enum whose variants are all skipped.
+    fn is_empty(&self) -> bool {
+        self.variants
+            .iter()
+            .all(|variant| matches!(&variant.0, Type::Union(ty) if ty.is_empty()))
+    }
+}
+
+impl Render for UnionType {
+    fn render(&self, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result {
+        // No value has a type without variants
+        if self.is_empty() {
+            return write!(f, "{}", BuiltinType::Never);
+        }
         for variant in &self.variants {
-            write!(f, "\n\t| {}", variant)?;
+            match &variant.0 {
+                // The variants of a nested union are variants of this one
+                Type::Union(ty) if ty.is_empty() => {}
+                Type::Union(ty) => ty.render(f, options)?,
+                ty => write!(f, "\n{}| {}", options.indentation(), Rendered(ty, options))?,
+            }
         }
         Ok(())
     }
@@ -456,37 +1191,39 @@ impl fmt::Display for UnionType {
 pub struct Variant(pub Type);
 
 impl Variant {
-    fn externally_tagged(rename_all: Renamer, variant: camo::Variant) -> Self {
-        let variant_renamer = match variant.attributes.rename {
-            Some(rename) => Renamer(Some(rename)),
-            None => rename_all,
-        };
-        let field_renamer = Renamer(variant.attributes.rename_all);
+    fn untagged(variant: camo::Variant, cx: Context<'_>) -> Self {
+        let field_renamer = Renamer(variant.attributes.rename_all_for(cx.direction));
+        match variant.content {
+            // Unit variants are serialized as `null`
+            camo::VariantContent::Unit => Self(Type::Builtin(BuiltinType::Null)),
+            camo::VariantContent::Unnamed(fields) => Self(Type::from_unnamed_fields(fields, cx)),
+            camo::VariantContent::Named(fields) => {
+                Self(Type::from_named_fields(field_renamer, fields, cx))
+            }
+        }
+    }
+
+    fn externally_tagged(rename_all: Renamer, variant: camo::Variant, cx: Context<'_>) -> Self {
+        let name = rename_all.rename_variant(&variant, cx.direction);
+        let field_renamer = Renamer(variant.attributes.rename_all_for(cx.direction));
         match variant.content {
-            camo::VariantContent::Unit => Self(Type::Literal(LiteralType::String(
-                variant_renamer.rename_type(variant.name),
-            ))),
-            camo::VariantContent::Unnamed(ty) => Self(Type::Object(ObjectType {
+            camo::VariantContent::Unit => Self(Type::Literal(LiteralType::String(name))),
+            camo::VariantContent::Unnamed(fields) => Self(Type::Object(ObjectType {
                 fields: Vec::from([Field {
-                    name: variant_renamer.rename_type(variant.name),
-                    ty: Type::from(ty),
+                    name,
+                    ty: Type::from_unnamed_fields(fields, cx),
                     optional: false,
+                    readonly: cx.options.is_readonly(),
+                    docs: Vec::new(),
                 }]),
             })),
             camo::VariantContent::Named(fields) => Self(Type::Object(ObjectType {
                 fields: Vec::from([Field {
-                    name: variant_renamer.rename_type(variant.name),
-                    ty: Type::Object(ObjectType {
-                        fields: fields
-                            .into_iter()
-                            .map(|field| Field {
-                                name: field_renamer.rename_field(field.name),
-                                ty: Type::from(field.ty),
-                                optional: false,
-                            })
-                            .collect(),
-                    }),
+                    name,
+                    ty: Type::from_named_fields(field_renamer, fields, cx),
                     optional: false,
+                    readonly: cx.options.is_readonly(),
+                    docs: Vec::new(),
                 }]),
             })),
         }
@@ -497,35 +1234,35 @@ impl Variant {
         tag: &'static str,
         content: &'static str,
         variant: camo::Variant,
+        cx: Context<'_>,
     ) -> Self {
-        let variant_renamer = match variant.attributes.rename {
-            Some(rename) => Renamer(Some(rename)),
-            None => rename_all,
-        };
-        let field_renamer = Renamer(variant.attributes.rename_all);
+        let name = rename_all.rename_variant(&variant, cx.direction);
+        let field_renamer = Renamer(variant.attributes.rename_all_for(cx.direction));
         match variant.content {
             camo::VariantContent::Unit => Self(Type::Object(ObjectType {
                 fields: Vec::from([Field {
                     name: String::from(tag),
-                    ty: Type::Literal(LiteralType::String(
-                        variant_renamer.rename_type(variant.name),
-                    )),
+                    ty: Type::Literal(LiteralType::String(name)),
                     optional: false,
+                    readonly: cx.options.is_readonly(),
+                    docs: Vec::new(),
                 }]),
             })),
-            camo::VariantContent::Unnamed(ty) => Self(Type::Object(ObjectType {
+            camo::VariantContent::Unnamed(fields) => Self(Type::Object(ObjectType {
                 fields: Vec::from([
                     Field {
                         name: String::from(tag),
-                        ty: Type::Literal(LiteralType::String(
-                            variant_renamer.rename_type(variant.name),
-                        )),
+                        ty: Type::Literal(LiteralType::String(name)),
                         optional: false,
+                        readonly: cx.options.is_readonly(),
+                        docs: Vec::new(),
                     },
                     Field {
                         name: String::from(content),
-                        ty: Type::from(ty),
+                        ty: Type::from_unnamed_fields(fields, cx),
                         optional: false,
+                        readonly: cx.options.is_readonly(),
+                        docs: Vec::new(),
                     },
                 ]),
             })),
@@ -533,86 +1270,85 @@ impl Variant {
                 fields: Vec::from([
                     Field {
                         name: String::from(tag),
-                        ty: Type::Literal(LiteralType::String(
-                            variant_renamer.rename_type(variant.name),
-                        )),
+                        ty: Type::Literal(LiteralType::String(name)),
                         optional: false,
+                        readonly: cx.options.is_readonly(),
+                        docs: Vec::new(),
                     },
                     Field {
                         name: String::from(content),
-                        ty: Type::Object(ObjectType {
-                            fields: fields
-                                .into_iter()
-                                .map(|field| Field {
-                                    name: field_renamer.rename_field(field.name),
-                                    ty: Type::from(field.ty),
-                                    optional: false,
-                                })
-                                .collect(),
-                        }),
+                        ty: Type::from_named_fields(field_renamer, fields, cx),
                         optional: false,
+                        readonly: cx.options.is_readonly(),
+                        docs: Vec::new(),
                     },
                 ]),
             })),
         }
     }
 
-    fn internally_tagged(rename_all: Renamer, tag: &'static str, variant: camo::Variant) -> Self {
-        let variant_renamer = match variant.attributes.rename {
-            Some(rename) => Renamer(Some(rename)),
-            None => rename_all,
-        };
-        let field_renamer = Renamer(variant.attributes.rename_all);
+    fn internally_tagged(
+        rename_all: Renamer,
+        tag: &'static str,
+        variant: camo::Variant,
+        cx: Context<'_>,
+    ) -> Self {
+        let name = rename_all.rename_variant(&variant, cx.direction);
+        let field_renamer = Renamer(variant.attributes.rename_all_for(cx.direction));
         match variant.content {
             camo::VariantContent::Unit => Self(Type::Object(ObjectType {
                 fields: Vec::from([Field {
                     name: String::from(tag),
-                    ty: Type::Literal(LiteralType::String(
-                        variant_renamer.rename_type(variant.name),
-                    )),
+                    ty: Type::Literal(LiteralType::String(name)),
                     optional: false,
+                    readonly: cx.options.is_readonly(),
+                    docs: Vec::new(),
                 }]),
             })),
-            camo::VariantContent::Unnamed(ty) => Self(Type::Intersection(IntersectionType {
-                left: Box::new(Type::Object(ObjectType {
+            // `serde` (and the derive macro) reject tuple variants in internally
+            // tagged enums, so no value matches a hand-written one (which is
+            // reported by `diagnostics`)
+            camo::VariantContent::Unnamed(fields) if fields.len() != 1 => {
+                Self(Type::Builtin(BuiltinType::Never))
+            }
+            camo::VariantContent::Unnamed(fields) => {
+                let tag = Type::Object(ObjectType {
                     fields: Vec::from([Field {
                         name: String::from(tag),
-                        ty: Type::Literal(LiteralType::String(
-                            variant_renamer.rename_type(variant.name),
-                        )),
+                        ty: Type::Literal(LiteralType::String(name)),
                         optional: false,
+                        readonly: cx.options.is_readonly(),
+                        docs: Vec::new(),
                     }]),
-                })),
-                right: Box::new(Type::from(ty)),
-            })),
+                });
+                match Type::from_unnamed_fields(fields, cx) {
+                    // A unit value contributes nothing besides the tag
+                    Type::Builtin(BuiltinType::Null) => Self(tag),
+                    ty => Self(Type::Intersection(IntersectionType {
+                        left: Box::new(tag),
+                        right: Box::new(ty),
+                    })),
+                }
+            }
             camo::VariantContent::Named(fields) => Self(Type::Intersection(IntersectionType {
                 left: Box::new(Type::Object(ObjectType {
                     fields: Vec::from([Field {
                         name: String::from(tag),
-                        ty: Type::Literal(LiteralType::String(
-                            variant_renamer.rename_type(variant.name),
-                        )),
+                        ty: Type::Literal(LiteralType::String(name)),
                         optional: false,
+                        readonly: cx.options.is_readonly(),
+                        docs: Vec::new(),
                     }]),
                 })),
-                right: Box::new(Type::Object(ObjectType {
-                    fields: fields
-                        .into_iter()
-                        .map(|field| Field {
-                            name: field_renamer.rename_field(field.name),
-                            ty: Type::from(field.ty),
-                            optional: false,
-                        })
-                        .collect(),
-                })),
+                right: Box::new(Type::from_named_fields(field_renamer, fields, cx)),
             })),
         }
     }
 }
 
-impl fmt::Display for Variant {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self.0)
+impl Render for Variant {
+    fn render(&self, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result {
+        self.0.render(f, options)
     }
 }
 
@@ -631,6 +1367,8 @@ pub enum Type {
     Literal(LiteralType),
     /// An array type.
     Array(ArrayType),
+    /// A tuple type.
+    Tuple(TupleType),
     /// A union type, combining multiple cases.
     Union(UnionType),
     /// An intersection type, combining two types.
@@ -638,8 +1376,122 @@ pub enum Type {
 }
 
 impl Type {
+    /// Whether the type is a union, which is written over several lines.
     fn is_union(&self) -> bool {
-        matches!(self, Self::Union(..))
+        matches!(self, Self::Union(ty) if !ty.is_empty())
+    }
+
+    /// Named fields are serialized as an object, which is intersected
+    /// with the types of any flattened fields.
+    fn from_named_fields(
+        rename_all: Renamer,
+        fields: Vec<camo::NamedField>,
+        cx: Context<'_>,
+    ) -> Self {
+        let (flattened, fields): (Vec<_>, Vec<_>) = fields
+            .into_iter()
+            .filter(|field| !field.attributes.is_skipped(cx.direction))
+            .partition(|field| field.attributes.flatten);
+
+        let object = Type::Object(ObjectType {
+            fields: fields
+                .into_iter()
+                .map(|field| Field::from_named(rename_all, field, cx))
+                .collect(),
+        });
+
+        let base = flattened
+            .into_iter()
+            .map(|field| Type::from_flattened(field.ty, cx))
+            .reduce(|left, right| {
+                Type::Intersection(IntersectionType {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                })
+            });
+
+        match (base, object) {
+            (None, object) => object,
+            // Leave out the empty object, as in `Base & {}`
+            (Some(base), Type::Object(ObjectType { fields })) if fields.is_empty() => base,
+            (Some(base), object) => Type::Intersection(IntersectionType {
+                left: Box::new(base),
+                right: Box::new(object),
+            }),
+        }
+    }
+
+    /// A flattened map contributes its entries as string-keyed properties,
+    /// while any other type contributes its own fields.
+    ///
+    /// A flattened optional value contributes either all of its fields or none
+    /// of them, which is described (somewhat loosely) as `Partial<T>`.
+    fn from_flattened(ty: camo::Type, cx: Context<'_>) -> Self {
+        match ty {
+            camo::Type::Map(ty) => Type::Path(TypePath::map(*ty.key, *ty.value, cx.options)),
+            camo::Type::Optional(ty) => {
+                Type::Path(TypePath::partial(Type::from_flattened(*ty.0, cx)))
+            }
+            camo::Type::Pointer(ty) => Type::from_flattened(*ty.0, cx),
+            ty => cx.ty(ty),
+        }
+    }
+
+    /// JSON object keys are always strings, so `serde_json` writes keys
+    /// that serialize as strings, numbers or booleans as strings.
+    /// Keys of any other type are named as they are, since they may be
+    /// e.g. an enum of unit variants.
+    ///
+    /// Returns `None` for keys that `serde_json` cannot serialize,
+    /// like sequences, tuples and optional values.
+    pub(crate) fn from_map_key(key: camo::Type) -> Option<Self> {
+        match key {
+            camo::Type::Path(path) => match camo::BuiltinType::try_from(path) {
+                Ok(_) => Some(Type::Builtin(BuiltinType::String)),
+                Err(path) => Some(Type::Path(TypePath::from(path))),
+            },
+            camo::Type::Builtin(_) => Some(Type::Builtin(BuiltinType::String)),
+            camo::Type::Reference(ty) => Type::from_map_key(*ty.ty),
+            camo::Type::Pointer(ty) => Type::from_map_key(*ty.0),
+            // Whatever the key is, it ends up as a string
+            camo::Type::Opaque => Some(Type::Builtin(BuiltinType::String)),
+            camo::Type::Slice(_)
+            | camo::Type::Array(_)
+            | camo::Type::Tuple(_)
+            | camo::Type::Sequence(_)
+            | camo::Type::Map(_)
+            | camo::Type::Optional(_) => None,
+        }
+    }
+
+    /// A single unnamed field is serialized as its content (a newtype),
+    /// while any other number of fields is serialized as a tuple.
+    ///
+    /// Skipped fields are left out of the tuple, which keeps being a tuple
+    /// even if only one field remains. `serde` ignores `skip` on the field
+    /// of a newtype, while a newtype whose field is left out with
+    /// `#[camo(skip)]` is described as an empty tuple.
+    fn from_unnamed_fields(fields: Vec<camo::UnnamedField>, cx: Context<'_>) -> Self {
+        let newtype = fields.len() == 1;
+        let mut types: Vec<_> = fields
+            .into_iter()
+            .filter(|field| {
+                if newtype {
+                    !field.attributes.camo_skip
+                } else {
+                    !field.attributes.is_skipped(cx.direction)
+                }
+            })
+            .map(|field| match field.attributes.typescript {
+                Some(ty) => Type::from(ty),
+                None => cx.ty(field.ty),
+            })
+            .collect();
+        if newtype && types.len() == 1 {
+            types.remove(0)
+        } else {
+            Type::Tuple(TupleType(types))
+        }
     }
 }
 
@@ -685,6 +1537,12 @@ impl From<ArrayType> for Type {
     }
 }
 
+impl From<TupleType> for Type {
+    fn from(value: TupleType) -> Self {
+        Self::Tuple(value)
+    }
+}
+
 impl From<IntersectionType> for Type {
     fn from(value: IntersectionType) -> Self {
         Self::Intersection(value)
@@ -693,72 +1551,82 @@ impl From<IntersectionType> for Type {
 
 impl From<camo::Type> for Type {
     fn from(ty: camo::Type) -> Self {
+        Type::from_type(ty, &Options::default())
+    }
+}
+
+impl Type {
+    /// Converts the type, describing built-in types as given by the options.
+    pub fn from_type(ty: camo::Type, options: &Options) -> Self {
         match ty {
             camo::Type::Path(ty) => match camo::BuiltinType::try_from(ty) {
-                Ok(ty) => Type::Builtin(BuiltinType::from(ty)),
-                Err(ty) => {
-                    if let Some(segment) = ty.segments.first() {
-                        match segment.name {
-                            "String" => {
-                                return Type::Builtin(BuiltinType::String);
-                            }
-                            "Vec" => {
-                                let component_ty = match segment.arguments.first().unwrap().clone()
-                                {
-                                    camo::GenericArgument::Type(ty) => ty,
-                                    camo::GenericArgument::Lifetime(_) => {
-                                        panic!("unexpected lifetime argument provided to Vec")
-                                    }
-                                };
-                                return Type::Array(ArrayType::from(Type::from(component_ty)));
-                            }
-                            "Option" => {
-                                let component_ty = match segment.arguments.first().unwrap().clone()
-                                {
-                                    camo::GenericArgument::Type(ty) => ty,
-                                    camo::GenericArgument::Lifetime(_) => {
-                                        panic!("unexpected lifetime argument provided to Option")
-                                    }
-                                };
-                                return Type::Union(UnionType {
-                                    variants: Vec::from([
-                                        Variant(Type::from(component_ty)),
-                                        Variant(Type::Builtin(BuiltinType::Null)),
-                                    ]),
-                                });
-                            }
-                            _ => return Type::Path(TypePath::from(ty)),
-                        }
-                    }
-                    Type::Path(TypePath::from(ty))
-                }
+                Ok(ty) => options.builtin_type(ty),
+                Err(ty) => Type::Path(TypePath::from_path(ty, options)),
             },
-            camo::Type::Reference(ty) => {
-                if let camo::Type::Path(path) = &*ty.ty {
-                    if let Some(segment) = path.segments.first() {
-                        if segment.name == "str" {
-                            return Type::Builtin(BuiltinType::String);
-                        }
+            camo::Type::Reference(ty) => Type::from_type(*ty.ty, options),
+            camo::Type::Slice(ty) => {
+                Type::Array(ArrayType(Box::new(Type::from_type(*ty.0, options))))
+            }
+            camo::Type::Array(ty) => {
+                let element = Type::from_type(*ty.ty, options);
+                match ty.length {
+                    // Arrays are serialized as tuples, so short ones can be described exactly
+                    Some(length) if length <= options.tuple_length() => {
+                        Type::Tuple(TupleType(vec![element; length]))
                     }
+                    _ => Type::Array(ArrayType(Box::new(element))),
+                }
+            }
+            camo::Type::Tuple(ty) => {
+                if ty.is_unit() {
+                    // `()` is serialized as `null`
+                    Type::Builtin(BuiltinType::Null)
+                } else {
+                    Type::Tuple(TupleType(
+                        ty.0.into_iter()
+                            .map(|ty| Type::from_type(ty, options))
+                            .collect(),
+                    ))
                 }
-                Type::from(*ty.ty)
             }
-            camo::Type::Slice(ty) => Type::Array(ArrayType::from(ty)),
-            camo::Type::Array(ty) => Type::Array(ArrayType::from(ty)),
+            camo::Type::Builtin(ty) => options.builtin_type(ty),
+            camo::Type::Sequence(ty) => {
+                Type::Array(ArrayType(Box::new(Type::from_type(*ty.0, options))))
+            }
+            camo::Type::Map(ty) => Type::Path(TypePath::map(*ty.key, *ty.value, options)),
+            camo::Type::Optional(ty) => {
+                let none = Variant(Type::Builtin(match options.optional() {
+                    OptionStyle::Undefined => BuiltinType::Undefined,
+                    OptionStyle::Null | OptionStyle::Optional => BuiltinType::Null,
+                }));
+                // `Some(None)` is serialized as `None`, so nested options
+                // (and other unions) are merged into one union
+                let mut variants = match Type::from_type(*ty.0, options) {
+                    Type::Union(ty) => ty.variants,
+                    ty => Vec::from([Variant(ty)]),
+                };
+                if !variants.contains(&none) {
+                    variants.push(none);
+                }
+                Type::Union(UnionType { variants })
+            }
+            camo::Type::Pointer(ty) => Type::from_type(*ty.0, options),
+            camo::Type::Opaque => Type::Builtin(BuiltinType::Unknown),
         }
     }
 }
 
-impl fmt::Display for Type {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl Render for Type {
+    fn render(&self, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result {
         match self {
             Type::Builtin(ty) => write!(f, "{}", ty),
-            Type::Path(ty) => write!(f, "{}", ty),
-            Type::Object(ty) => write!(f, "{}", ty),
-            Type::Literal(ty) => write!(f, "{}", ty),
-            Type::Array(ty) => write!(f, "{}", ty),
-            Type::Union(ty) => write!(f, "{}", ty),
-            Type::Intersection(ty) => write!(f, "{}", ty),
+            Type::Path(ty) => ty.render(f, options),
+            Type::Object(ty) => ty.render(f, options),
+            Type::Literal(ty) => ty.render(f, options),
+            Type::Array(ty) => ty.render(f, options),
+            Type::Tuple(ty) => ty.render(f, options),
+            Type::Union(ty) => ty.render(f, options),
+            Type::Intersection(ty) => ty.render(f, options),
         }
     }
 }
@@ -827,7 +1695,7 @@ impl From<camo::BuiltinType> for BuiltinType {
             | camo::BuiltinType::Isize
             | camo::BuiltinType::F32
             | camo::BuiltinType::F64 => BuiltinType::Number,
-            camo::BuiltinType::Char => BuiltinType::String,
+            camo::BuiltinType::Char | camo::BuiltinType::String => BuiltinType::String,
         }
     }
 }
@@ -852,14 +1720,55 @@ pub struct TypePath {
     pub segments: Vec<PathSegment>,
 }
 
-impl From<camo::TypePath> for TypePath {
-    fn from(value: camo::TypePath) -> Self {
+impl TypePath {
+    /// The `Record<K, V>` utility type.
+    pub fn record(key: Type, value: Type) -> Self {
         Self {
-            segments: value.segments.into_iter().map(Into::into).collect(),
+            segments: Vec::from([PathSegment {
+                name: String::from("Record"),
+                arguments: Vec::from([key, value]),
+            }]),
+        }
+    }
+
+    /// The `Partial<T>` utility type.
+    pub fn partial(ty: Type) -> Self {
+        Self {
+            segments: Vec::from([PathSegment {
+                name: String::from("Partial"),
+                arguments: Vec::from([ty]),
+            }]),
+        }
+    }
+
+    /// The `Record<K, V>` utility type for a map with the given key and value types.
+    ///
+    /// Keys that `serde_json` cannot serialize (as reported by
+    /// [`diagnostics`](crate::diagnostics)) are given the type `string`.
+    fn map(key: camo::Type, value: camo::Type, options: &Options) -> Self {
+        Self::record(
+            Type::from_map_key(key).unwrap_or(Type::Builtin(BuiltinType::String)),
+            Type::from_type(value, options),
+        )
+    }
+
+    fn from_path(value: camo::TypePath, options: &Options) -> Self {
+        Self {
+            segments: value
+                .segments
+                .into_iter()
+                .map(|segment| PathSegment::from_segment(segment, options))
+                .collect(),
         }
     }
 }
 
+impl From<camo::TypePath> for TypePath {
+    fn from(value: camo::TypePath) -> Self {
+        TypePath::from_path(value, &Options::default())
+    }
+}
+
 impl<const N: usize> From<[&str; N]> for TypePath {
     fn from(value: [&str; N]) -> Self {
         Self {
@@ -895,14 +1804,14 @@ impl From<String> for TypePath {
     }
 }
 
-impl fmt::Display for TypePath {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl Render for TypePath {
+    fn render(&self, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result {
         let mut iter = self.segments.iter();
         if let Some(segment) = iter.next() {
-            write!(f, "{}", segment)?;
+            segment.render(f, options)?;
         }
         for segment in iter {
-            write!(f, ".{}", segment)?;
+            write!(f, ".{}", Rendered(segment, options))?;
         }
         Ok(())
     }
@@ -919,13 +1828,19 @@ pub struct PathSegment {
 
 impl From<camo::PathSegment> for PathSegment {
     fn from(value: camo::PathSegment) -> Self {
+        PathSegment::from_segment(value, &Options::default())
+    }
+}
+
+impl PathSegment {
+    fn from_segment(value: camo::PathSegment, options: &Options) -> Self {
         Self {
             name: value.name.to_string(),
             arguments: value
                 .arguments
                 .into_iter()
                 .filter_map(|argument| match argument {
-                    camo::GenericArgument::Type(ty) => Some(Type::from(ty)),
+                    camo::GenericArgument::Type(ty) => Some(Type::from_type(ty, options)),
                     camo::GenericArgument::Lifetime(_) => None,
                 })
                 .collect(),
@@ -933,17 +1848,18 @@ impl From<camo::PathSegment> for PathSegment {
     }
 }
 
-impl fmt::Display for PathSegment {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl Render for PathSegment {
+    fn render(&self, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result {
         write!(f, "{}", self.name)?;
         if !self.arguments.is_empty() {
             write!(f, "<")?;
             let mut iter = self.arguments.iter();
             if let Some(argument) = iter.next() {
-                write!(f, "{}", argument)?;
+                render_nested(argument, f, options)?;
             }
             for argument in iter {
-                write!(f, ", {}", argument)?;
+                write!(f, ", ")?;
+                render_nested(argument, f, options)?;
             }
             write!(f, ">")?;
         }
@@ -960,11 +1876,12 @@ pub struct ObjectType {
     pub fields: Vec<Field>,
 }
 
-impl fmt::Display for ObjectType {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl Render for ObjectType {
+    fn render(&self, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result {
         write!(f, "{{")?;
         for field in &self.fields {
-            write!(f, " {}", field)?;
+            write!(f, " ")?;
+            field.render_declaration(f, options)?;
         }
         write!(f, " }}")
     }
@@ -982,12 +1899,15 @@ impl fmt::Display for ObjectType {
 pub enum LiteralType {
     /// A string literal type.
     String(String),
+    /// A numeric literal type.
+    Number(i128),
 }
 
-impl fmt::Display for LiteralType {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+impl Render for LiteralType {
+    fn render(&self, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result {
         match self {
-            LiteralType::String(s) => write!(f, "\"{}\"", s),
+            LiteralType::String(s) => render_string(s, f, options),
+            LiteralType::Number(n) => write!(f, "{}", n),
         }
     }
 }
@@ -1013,15 +1933,65 @@ impl From<camo::SliceType> for ArrayType {
     }
 }
 
+impl From<camo::SequenceType> for ArrayType {
+    fn from(value: camo::SequenceType) -> Self {
+        Self(Box::new(Type::from(*value.0)))
+    }
+}
+
 impl From<camo::ArrayType> for ArrayType {
     fn from(value: camo::ArrayType) -> Self {
-        Self(Box::new(Type::from(*value.0)))
+        Self(Box::new(Type::from(*value.ty)))
     }
 }
 
-impl fmt::Display for ArrayType {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}[]", self.0)
+impl Render for ArrayType {
+    fn render(&self, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result {
+        // `[]` binds more tightly than `|` and `&`
+        match &*self.0 {
+            Type::Union(_) | Type::Intersection(_) => {
+                write!(f, "({})[]", Rendered(&*self.0, options))
+            }
+            ty => write!(f, "{}[]", Rendered(ty, options)),
+        }
+    }
+}
+
+/// A tuple type expression.
+///
+/// Example:
+/// ```ts
+/// type Point = [number, number];
+/// ```
+///
+/// See: <https://www.typescriptlang.org/docs/handbook/2/objects.html#tuple-types>
+#[derive(Clone, Debug, PartialEq)]
+pub struct TupleType(pub Vec<Type>);
+
+impl From<Vec<Type>> for TupleType {
+    fn from(value: Vec<Type>) -> Self {
+        Self(value)
+    }
+}
+
+impl From<camo::TupleType> for TupleType {
+    fn from(value: camo::TupleType) -> Self {
+        Self(value.0.into_iter().map(Type::from).collect())
+    }
+}
+
+impl Render for TupleType {
+    fn render(&self, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result {
+        write!(f, "[")?;
+        let mut iter = self.0.iter();
+        if let Some(ty) = iter.next() {
+            render_nested(ty, f, options)?;
+        }
+        for ty in iter {
+            write!(f, ", ")?;
+            render_nested(ty, f, options)?;
+        }
+        write!(f, "]")
     }
 }
 
@@ -1042,8 +2012,69 @@ pub struct IntersectionType {
     pub right: Box<Type>,
 }
 
-impl fmt::Display for IntersectionType {
+impl Render for IntersectionType {
+    fn render(&self, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result {
+        // `&` binds more tightly than `|`
+        render_nested(&self.left, f, options)?;
+        write!(f, " & ")?;
+        render_nested(&self.right, f, options)
+    }
+}
+
+/// Writes a type that is part of another type (e.g. an operand of `&`,
+/// an element of a tuple or a type argument), where a union, which is
+/// written over several lines, is parenthesized.
+fn render_nested(ty: &Type, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result {
+    match ty {
+        Type::Union(_) => write!(f, "({})", Rendered(ty, options)),
+        ty => ty.render(f, options),
+    }
+}
+
+/// Writes a value with the given options,
+/// which decide e.g. the indentation and quotes.
+trait Render {
+    fn render(&self, f: &mut fmt::Formatter<'_>, options: &Options) -> fmt::Result;
+}
+
+struct Rendered<'a, T>(&'a T, &'a Options);
+
+impl<T: Render> fmt::Display for Rendered<'_, T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{} & {}", self.left, self.right)
+        let Self(value, options) = self;
+        value.render(f, options)
     }
 }
+
+macro_rules! display {
+    ($($ty:ty,)*) => {
+        $(
+            impl fmt::Display for $ty {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    self.render(f, &Options::default())
+                }
+            }
+        )*
+    };
+}
+
+display! {
+    Definition,
+    Interface,
+    Field,
+    TypeParameter,
+    TypeAlias,
+    Enum,
+    Values,
+    Const,
+    UnionType,
+    Variant,
+    Type,
+    TypePath,
+    PathSegment,
+    ObjectType,
+    LiteralType,
+    ArrayType,
+    TupleType,
+    IntersectionType,
+}
@@ -0,0 +1,190 @@
+use camo_core as camo;
+use std::fmt;
+
+use crate::ast::Type;
+
+/// A problem with a type definition, which does not prevent it from
+/// being converted, but means that the resulting TypeScript definition
+/// does not describe what `serde_json` produces.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Diagnostic {
+    /// A description of the problem.
+    pub message: String,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Checks a type definition for problems that cannot be expressed
+/// in the TypeScript definition, such as map keys that `serde_json`
+/// cannot serialize, or tuple variants in an internally tagged enum.
+///
+/// Fields and variants that are left out in both directions
+/// (e.g. with `#[serde(skip)]`) are not checked.
+///
+/// Example:
+///
+/// ```
+/// use camo_core::{
+///     Container, Item, MapType, NamedField, Struct, StructContent, TupleType, Type, Visibility,
+/// };
+/// use camo_typescript::diagnostics;
+///
+/// let container = Container {
+///     module_path: "example",
+///     attributes: Default::default(), docs: Default::default(),
+///     item: Item::Struct(Struct {
+///         visibility: Visibility::Pub,
+///         name: "Grid",
+///         parameters: Vec::new(),
+///         content: StructContent::NamedFields(Vec::from([NamedField {
+///             attributes: Default::default(), docs: Default::default(),
+///             name: "cells",
+///             ty: Type::Map(MapType {
+///                 key: Box::new(Type::Tuple(TupleType::unit())),
+///                 value: Box::new(Type::Tuple(TupleType::unit())),
+///             }),
+///         }])),
+///     }),
+/// };
+///
+/// let diagnostics = diagnostics(&container);
+/// assert_eq!(diagnostics.len(), 1);
+/// assert_eq!(
+///     diagnostics[0].to_string(),
+///     "`Grid`: the map keys in field `cells` cannot be serialized by `serde_json`, \
+///     which requires keys that serialize as strings, numbers or booleans",
+/// );
+/// ```
+pub fn diagnostics(container: &camo::Container) -> Vec<Diagnostic> {
+    let mut checker = Checker {
+        name: container.item.name(),
+        diagnostics: Vec::new(),
+    };
+    match &container.item {
+        camo::Item::Struct(ty) => match &ty.content {
+            camo::StructContent::NamedFields(fields) => {
+                checker.check_named_fields(fields, None);
+            }
+            camo::StructContent::UnnamedFields(fields) => {
+                checker.check_unnamed_fields(fields, None);
+            }
+            camo::StructContent::Unit => {}
+        },
+        camo::Item::Enum(ty) => {
+            let attributes = &container.attributes;
+            let internally_tagged = attributes.tag.is_some()
+                && attributes.content.is_none()
+                && !attributes.untagged
+                && !attributes.repr;
+            for variant in &ty.variants {
+                if is_skipped(|direction| variant.attributes.is_skipped(direction)) {
+                    continue;
+                }
+                let is_tuple = matches!(
+                    &variant.content,
+                    camo::VariantContent::Unnamed(fields) if fields.len() != 1
+                );
+                if internally_tagged && is_tuple && !variant.attributes.untagged {
+                    checker.diagnostics.push(Diagnostic {
+                        message: format!(
+                            "`{}`: variant `{}` is a tuple variant, which `serde` cannot \
+                            serialize in an internally tagged enum, so it is described as `never`",
+                            checker.name, variant.name
+                        ),
+                    });
+                }
+                match &variant.content {
+                    camo::VariantContent::Named(fields) => {
+                        checker.check_named_fields(fields, Some(variant.name));
+                    }
+                    camo::VariantContent::Unnamed(fields) => {
+                        checker.check_unnamed_fields(fields, Some(variant.name));
+                    }
+                    camo::VariantContent::Unit => {}
+                }
+            }
+        }
+    }
+    checker.diagnostics
+}
+
+struct Checker {
+    name: &'static str,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Checker {
+    fn check_named_fields(&mut self, fields: &[camo::NamedField], variant: Option<&str>) {
+        for field in fields {
+            if is_skipped(|direction| field.attributes.is_skipped(direction)) {
+                continue;
+            }
+            self.check(&field.ty, &location(format!("`{}`", field.name), variant));
+        }
+    }
+
+    fn check_unnamed_fields(&mut self, fields: &[camo::UnnamedField], variant: Option<&str>) {
+        for (index, field) in fields.iter().enumerate() {
+            if is_skipped(|direction| field.attributes.is_skipped(direction)) {
+                continue;
+            }
+            self.check(&field.ty, &location(index.to_string(), variant));
+        }
+    }
+
+    fn check(&mut self, ty: &camo::Type, location: &str) {
+        match ty {
+            camo::Type::Path(path) => {
+                for segment in &path.segments {
+                    for argument in &segment.arguments {
+                        if let camo::GenericArgument::Type(ty) = argument {
+                            self.check(ty, location);
+                        }
+                    }
+                }
+            }
+            camo::Type::Reference(ty) => self.check(&ty.ty, location),
+            camo::Type::Slice(ty) => self.check(&ty.0, location),
+            camo::Type::Array(ty) => self.check(&ty.ty, location),
+            camo::Type::Tuple(ty) => {
+                for ty in &ty.0 {
+                    self.check(ty, location);
+                }
+            }
+            camo::Type::Builtin(_) | camo::Type::Opaque => {}
+            camo::Type::Sequence(ty) => self.check(&ty.0, location),
+            camo::Type::Map(ty) => {
+                if Type::from_map_key((*ty.key).clone()).is_none() {
+                    self.diagnostics.push(Diagnostic {
+                        message: format!(
+                            "`{}`: the map keys in {} cannot be serialized by `serde_json`, \
+                            which requires keys that serialize as strings, numbers or booleans",
+                            self.name, location
+                        ),
+                    });
+                }
+                self.check(&ty.key, location);
+                self.check(&ty.value, location);
+            }
+            camo::Type::Optional(ty) => self.check(&ty.0, location),
+            camo::Type::Pointer(ty) => self.check(&ty.0, location),
+        }
+    }
+}
+
+/// Whether something is left out of the definitions in both directions,
+/// so that it is never passed to `serde_json`.
+fn is_skipped(is_skipped_for: impl Fn(camo::Direction) -> bool) -> bool {
+    is_skipped_for(camo::Direction::Serialize) && is_skipped_for(camo::Direction::Deserialize)
+}
+
+fn location(field: String, variant: Option<&str>) -> String {
+    match variant {
+        Some(variant) => format!("field {} of variant `{}`", field, variant),
+        None => format!("field {}", field),
+    }
+}
@@ -4,5 +4,9 @@
 //! i. e. a subset of the TypeScript abstract syntax.
 
 mod ast;
+mod diagnostic;
+mod options;
 
 pub use ast::*;
+pub use diagnostic::*;
+pub use options::*;
@@ -0,0 +1,288 @@
+use camo_core as camo;
+
+use crate::ast::{BuiltinType, Type};
+
+/// Options for the conversion of Rust type definitions into TypeScript.
+///
+/// By default, every numeric type is described as `number`. Since a `number`
+/// cannot represent integers past 2^53 exactly, 64- and 128-bit integers can
+/// instead be described as e.g. `bigint` (with a JSON parser that supports it)
+/// or `string` (when they are serialized as strings):
+///
+/// ```
+/// use camo::{core::BuiltinType, Camo};
+/// use camo::core::Direction;
+/// use camo_typescript::{BuiltinType as TsBuiltinType, Definition, Options};
+///
+/// #[derive(Camo)]
+/// struct User {
+///     id: u64,
+///     age: u64,
+/// }
+///
+/// let options = Options::new()
+///     .builtin(BuiltinType::U64, TsBuiltinType::BigInt)
+///     .field(User::identity(), "age", TsBuiltinType::Number);
+///
+/// let definition = Definition::from_container_with(User::camo(), Direction::Serialize, &options);
+/// assert_eq!(
+///     definition.to_string(),
+///     "interface User {\n\tid: bigint;\n\tage: number;\n}\n",
+/// );
+/// ```
+///
+/// The remaining options decide the shape of the definitions, while
+/// indentation and quotes are applied when rendering them with
+/// [`Definition::display_with`](crate::Definition::display_with):
+///
+/// ```
+/// use camo::Camo;
+/// use camo_typescript::{Export, IntoDefinition, OptionStyle, Options, Quote};
+///
+/// #[derive(Camo)]
+/// struct User {
+///     name: Option<String>,
+///     #[serde(rename = "e-mail")]
+///     email: String,
+/// }
+///
+/// let options = Options::new()
+///     .option_style(OptionStyle::Optional)
+///     .interfaces(false)
+///     .indent("  ")
+///     .quote(Quote::Single)
+///     .readonly(true)
+///     .export(Export::All);
+///
+/// let definition = User::camo().into_definition_with(&options);
+/// assert_eq!(
+///     definition.display_with(&options).to_string(),
+///     "export type User = { readonly name?: string; readonly 'e-mail': string; };\n",
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct Options {
+    builtins: Vec<(camo::BuiltinType, Type)>,
+    fields: Vec<(camo::TypeIdentity, String, Type)>,
+    option_style: OptionStyle,
+    interfaces: bool,
+    indent: String,
+    quote: Quote,
+    readonly: bool,
+    export: Export,
+    enum_style: EnumStyle,
+    tuple_length: usize,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            builtins: Vec::new(),
+            fields: Vec::new(),
+            option_style: OptionStyle::Null,
+            interfaces: true,
+            indent: String::from("\t"),
+            quote: Quote::Double,
+            readonly: false,
+            export: Export::Public,
+            enum_style: EnumStyle::Union,
+            tuple_length: 8,
+        }
+    }
+}
+
+impl Options {
+    /// The default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Describe `Option<T>` as given by `style`. By default, this is `T | null`.
+    pub fn option_style(self, style: OptionStyle) -> Self {
+        Self {
+            option_style: style,
+            ..self
+        }
+    }
+
+    /// Whether structs with named fields are defined as interfaces (the default),
+    /// or as type aliases of object types.
+    pub fn interfaces(self, interfaces: bool) -> Self {
+        Self { interfaces, ..self }
+    }
+
+    /// Indent with `indent` instead of a tab.
+    pub fn indent<S: Into<String>>(self, indent: S) -> Self {
+        Self {
+            indent: indent.into(),
+            ..self
+        }
+    }
+
+    /// Quote string literal types and property names with `quote`
+    /// instead of double quotes.
+    pub fn quote(self, quote: Quote) -> Self {
+        Self { quote, ..self }
+    }
+
+    /// Whether every property is marked as `readonly`.
+    pub fn readonly(self, readonly: bool) -> Self {
+        Self { readonly, ..self }
+    }
+
+    /// Describe enums that are serialized as plain strings or numbers
+    /// (i.e. enums of unit variants, which are externally tagged or
+    /// `#[camo(repr)]`) as given by `style`. By default, they are described
+    /// as a union of literal types.
+    pub fn enum_style(self, style: EnumStyle) -> Self {
+        Self {
+            enum_style: style,
+            ..self
+        }
+    }
+
+    /// Describe arrays of up to `length` elements (8 by default) as tuples,
+    /// e.g. `[f32; 3]` as `[number, number, number]`, and longer arrays
+    /// (or arrays whose length is not a literal) as e.g. `number[]`.
+    pub fn max_tuple_length(self, length: usize) -> Self {
+        Self {
+            tuple_length: length,
+            ..self
+        }
+    }
+
+    /// Decide which definitions are marked with `export` as given by `export`.
+    /// By default, definitions of `pub` types are exported.
+    ///
+    /// A `#[camo(export = ...)]` attribute on a type takes precedence over this.
+    pub fn export(self, export: Export) -> Self {
+        Self { export, ..self }
+    }
+
+    /// Describe the built-in Rust type `builtin` (e.g. `u64`) as `ty`
+    /// wherever it occurs.
+    pub fn builtin<T: Into<Type>>(mut self, builtin: camo::BuiltinType, ty: T) -> Self {
+        self.builtins.retain(|(existing, _)| *existing != builtin);
+        self.builtins.push((builtin, ty.into()));
+        self
+    }
+
+    /// Describe the named field `field` of the Rust type identified by
+    /// `container` (e.g. `User::identity()`) as `ty`, regardless of its Rust type.
+    ///
+    /// This takes precedence over [`Options::builtin`] and over a
+    /// `#[camo(typescript = "...")]` attribute on the field. The field name is
+    /// the name in Rust, before any `serde` renames are applied.
+    pub fn field<T: Into<Type>>(
+        mut self,
+        container: camo::TypeIdentity,
+        field: &str,
+        ty: T,
+    ) -> Self {
+        self.fields
+            .retain(|(existing, name, _)| *existing != container || name != field);
+        self.fields.push((container, field.to_string(), ty.into()));
+        self
+    }
+
+    pub(crate) fn builtin_type(&self, builtin: camo::BuiltinType) -> Type {
+        self.builtins
+            .iter()
+            .find(|(existing, _)| *existing == builtin)
+            .map(|(_, ty)| ty.clone())
+            .unwrap_or_else(|| Type::Builtin(BuiltinType::from(builtin)))
+    }
+
+    pub(crate) fn tuple_length(&self) -> usize {
+        self.tuple_length
+    }
+
+    pub(crate) fn enums(&self) -> EnumStyle {
+        self.enum_style
+    }
+
+    pub(crate) fn optional(&self) -> OptionStyle {
+        self.option_style
+    }
+
+    pub(crate) fn uses_interfaces(&self) -> bool {
+        self.interfaces
+    }
+
+    pub(crate) fn indentation(&self) -> &str {
+        &self.indent
+    }
+
+    pub(crate) fn quote_char(&self) -> char {
+        match self.quote {
+            Quote::Double => '"',
+            Quote::Single => '\'',
+        }
+    }
+
+    pub(crate) fn is_readonly(&self) -> bool {
+        self.readonly
+    }
+
+    pub(crate) fn is_exported(&self, visibility: &camo::Visibility) -> bool {
+        match self.export {
+            Export::Public => visibility.is_pub(),
+            Export::All => true,
+            Export::None => false,
+        }
+    }
+
+    pub(crate) fn field_type(&self, container: camo::TypeIdentity, field: &str) -> Option<Type> {
+        self.fields
+            .iter()
+            .find(|(existing, name, _)| *existing == container && name == field)
+            .map(|(_, _, ty)| ty.clone())
+    }
+}
+
+/// How `Option<T>` is described.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum OptionStyle {
+    /// `T | null`, which is how `serde_json` serializes `None`.
+    Null,
+    /// `T | undefined`, for options that are left out rather than
+    /// serialized as `null` (e.g. with `skip_serializing_if = "Option::is_none"`).
+    Undefined,
+    /// An optional property (`name?: T`) for named fields,
+    /// and `T | null` anywhere else.
+    Optional,
+}
+
+/// The quotes around string literal types and property names.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Quote {
+    /// Double quotes, e.g. `"Tag"`.
+    Double,
+    /// Single quotes, e.g. `'Tag'`.
+    Single,
+}
+
+/// Which definitions are marked with `export`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Export {
+    /// Definitions of `pub` types.
+    Public,
+    /// Every definition.
+    All,
+    /// No definitions.
+    None,
+}
+
+/// How enums that are serialized as plain strings or numbers are described.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum EnumStyle {
+    /// A union of literal types, e.g. `type Direction = "Up" | "Down";`.
+    Union,
+    /// A union of literal types, along with a constant that lists the values,
+    /// e.g. `const ALL_DIRECTIONS = ["Up", "Down"] as const;`.
+    UnionWithValues,
+    /// An `enum`, e.g. `enum Direction { Up = "Up", Down = "Down" }`.
+    Enum,
+    /// A `const enum`, which is inlined where it is used.
+    ConstEnum,
+}
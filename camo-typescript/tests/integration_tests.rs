@@ -3,7 +3,8 @@
 use camo::Camo;
 use camo_typescript::{
     ArrayType, BuiltinType, Definition, Field, Interface, IntersectionType, LiteralType,
-    ObjectType, PathSegment, Type, TypeAlias, TypePath, UnionType, Variant,
+    ObjectType, PathSegment, TupleType, Type, TypeAlias, TypeParameter, TypePath, UnionType,
+    Variant,
 };
 use serde::{Deserialize, Serialize};
 
@@ -18,12 +19,15 @@ fn implements_from() {
         Definition::from(Foo::camo()),
         Definition::Interface(Interface {
             export: false,
+            docs: Vec::new(),
             name: String::from("Foo"),
             parameters: Vec::new(),
             fields: vec![Field {
                 name: String::from("foo"),
                 ty: Type::Builtin(BuiltinType::Number),
                 optional: false,
+                readonly: false,
+                docs: Vec::new(),
             },],
         },)
     );
@@ -42,12 +46,15 @@ fn supports_booleans() {
         def,
         Definition::Interface(Interface {
             export: false,
+            docs: Vec::new(),
             name: String::from("Foo"),
             parameters: Vec::new(),
             fields: vec![Field {
                 name: String::from("bar"),
                 ty: Type::Builtin(BuiltinType::Boolean),
                 optional: false,
+                readonly: false,
+                docs: Vec::new(),
             }]
         })
     );
@@ -68,6 +75,7 @@ fn supports_numbers() {
         def,
         Definition::Interface(Interface {
             export: false,
+            docs: Vec::new(),
             name: String::from("Foo"),
             parameters: Vec::new(),
             fields: vec![
@@ -75,16 +83,22 @@ fn supports_numbers() {
                     name: String::from("foo"),
                     ty: Type::Builtin(BuiltinType::Number),
                     optional: false,
+                    readonly: false,
+                    docs: Vec::new(),
                 },
                 Field {
                     name: String::from("bar"),
                     ty: Type::Builtin(BuiltinType::Number),
                     optional: false,
+                    readonly: false,
+                    docs: Vec::new(),
                 },
                 Field {
                     name: String::from("baz"),
                     ty: Type::Builtin(BuiltinType::Number),
                     optional: false,
+                    readonly: false,
+                    docs: Vec::new(),
                 },
             ],
         })
@@ -104,12 +118,15 @@ fn supports_chars() {
         def,
         Definition::Interface(Interface {
             export: false,
+            docs: Vec::new(),
             name: String::from("Foo"),
             parameters: Vec::new(),
             fields: vec![Field {
                 name: String::from("foo"),
                 ty: Type::Builtin(BuiltinType::String),
                 optional: false,
+                readonly: false,
+                docs: Vec::new(),
             }]
         })
     );
@@ -128,12 +145,15 @@ fn supports_string() {
         def,
         Definition::Interface(Interface {
             export: false,
+            docs: Vec::new(),
             name: String::from("Foo"),
             parameters: Vec::new(),
             fields: vec![Field {
                 name: String::from("foo"),
                 ty: Type::Builtin(BuiltinType::String),
                 optional: false,
+                readonly: false,
+                docs: Vec::new(),
             }]
         })
     );
@@ -152,12 +172,15 @@ fn supports_str() {
         def,
         Definition::Interface(Interface {
             export: false,
+            docs: Vec::new(),
             name: String::from("Foo"),
             parameters: Vec::new(),
             fields: vec![Field {
                 name: String::from("foo"),
                 ty: Type::Builtin(BuiltinType::String),
                 optional: false,
+                readonly: false,
+                docs: Vec::new(),
             }]
         })
     );
@@ -176,12 +199,15 @@ fn supports_vec() {
         def,
         Definition::Interface(Interface {
             export: false,
+            docs: Vec::new(),
             name: String::from("Foo"),
             parameters: Vec::new(),
             fields: vec![Field {
                 name: String::from("foo"),
                 ty: Type::Array(ArrayType::from(Type::Builtin(BuiltinType::Number))),
                 optional: false,
+                readonly: false,
+                docs: Vec::new(),
             }]
         })
     );
@@ -200,12 +226,15 @@ fn supports_slice() {
         def,
         Definition::Interface(Interface {
             export: false,
+            docs: Vec::new(),
             name: String::from("Foo"),
             parameters: Vec::new(),
             fields: vec![Field {
                 name: String::from("foo"),
                 ty: Type::Array(ArrayType::from(Type::Builtin(BuiltinType::Number))),
                 optional: false,
+                readonly: false,
+                docs: Vec::new(),
             }]
         })
     );
@@ -224,17 +253,59 @@ fn supports_array() {
         def,
         Definition::Interface(Interface {
             export: false,
+            docs: Vec::new(),
             name: String::from("Foo"),
             parameters: Vec::new(),
             fields: vec![Field {
                 name: String::from("foo"),
                 ty: Type::Array(ArrayType::from(Type::Builtin(BuiltinType::Number))),
                 optional: false,
+                readonly: false,
+                docs: Vec::new(),
             }]
         })
     );
 }
 
+#[test]
+fn supports_fixed_length_array() {
+    use camo_typescript::Options;
+    use unindent::Unindent;
+
+    #[derive(Camo)]
+    struct Vertex {
+        position: [f32; 3],
+        empty: [u8; 0],
+        hash: [u8; 32],
+    }
+
+    assert_eq!(
+        Definition::from(Vertex::camo()).to_string(),
+        "
+        interface Vertex {
+        \tposition: [number, number, number];
+        \tempty: [];
+        \thash: number[];
+        }
+        "
+        .unindent()
+    );
+
+    let options = Options::new().max_tuple_length(2);
+    assert_eq!(
+        Definition::from_container_with(Vertex::camo(), camo::core::Direction::Serialize, &options)
+            .to_string(),
+        "
+        interface Vertex {
+        \tposition: number[];
+        \tempty: [];
+        \thash: number[];
+        }
+        "
+        .unindent()
+    );
+}
+
 #[test]
 fn supports_option() {
     #[derive(Camo)]
@@ -248,6 +319,7 @@ fn supports_option() {
         def,
         Definition::Interface(Interface {
             export: false,
+            docs: Vec::new(),
             name: String::from("Foo"),
             parameters: Vec::new(),
             fields: vec![Field {
@@ -259,19 +331,131 @@ fn supports_option() {
                     ])
                 }),
                 optional: false,
+                readonly: false,
+                docs: Vec::new(),
+            }]
+        })
+    );
+}
+
+#[test]
+fn supports_tuple() {
+    #[derive(Camo)]
+    struct Foo {
+        foo: (f64, String),
+    }
+
+    let def: Definition = Foo::camo().into();
+
+    assert_eq!(
+        def,
+        Definition::Interface(Interface {
+            export: false,
+            docs: Vec::new(),
+            name: String::from("Foo"),
+            parameters: Vec::new(),
+            fields: vec![Field {
+                name: String::from("foo"),
+                ty: Type::Tuple(TupleType(Vec::from([
+                    Type::Builtin(BuiltinType::Number),
+                    Type::Builtin(BuiltinType::String),
+                ]))),
+                optional: false,
+                readonly: false,
+                docs: Vec::new(),
+            }]
+        })
+    );
+}
+
+#[test]
+fn supports_unit() {
+    #[derive(Camo)]
+    struct Foo {
+        foo: (),
+    }
+
+    let def: Definition = Foo::camo().into();
+
+    assert_eq!(
+        def,
+        Definition::Interface(Interface {
+            export: false,
+            docs: Vec::new(),
+            name: String::from("Foo"),
+            parameters: Vec::new(),
+            fields: vec![Field {
+                name: String::from("foo"),
+                ty: Type::Builtin(BuiltinType::Null),
+                optional: false,
+                readonly: false,
+                docs: Vec::new(),
             }]
         })
     );
 }
 
+#[test]
+fn display_tuple() {
+    let ty = Type::Tuple(TupleType(Vec::from([
+        Type::Builtin(BuiltinType::Number),
+        Type::Builtin(BuiltinType::Number),
+    ])));
+
+    assert_eq!(ty.to_string(), "[number, number]");
+}
+
+#[test]
+fn supports_tuple_struct() {
+    #[derive(Camo)]
+    struct Point(i32, i32);
+
+    let def: Definition = Point::camo().into();
+
+    assert_eq!(
+        def,
+        Definition::Alias(TypeAlias {
+            export: false,
+            docs: Vec::new(),
+            name: String::from("Point"),
+            parameters: Vec::new(),
+            ty: Type::Tuple(TupleType(Vec::from([
+                Type::Builtin(BuiltinType::Number),
+                Type::Builtin(BuiltinType::Number),
+            ]))),
+        })
+    );
+}
+
+#[test]
+fn supports_unit_struct() {
+    #[derive(Camo)]
+    struct Ping;
+
+    let def: Definition = Ping::camo().into();
+
+    assert_eq!(
+        def,
+        Definition::Alias(TypeAlias {
+            export: false,
+            docs: Vec::new(),
+            name: String::from("Ping"),
+            parameters: Vec::new(),
+            ty: Type::Builtin(BuiltinType::Null),
+        })
+    );
+    assert_eq!(def.to_string(), "type Ping = null;\n");
+}
+
 #[test]
 fn display_type_alias() {
     use unindent::Unindent;
 
     let def = TypeAlias {
         export: true,
+        docs: Vec::new(),
         name: String::from("Foo"),
-        parameters: Vec::from(["K"]),
+        parameters: Vec::from([TypeParameter::from("K")]),
         ty: Type::Object(ObjectType {
             fields: Vec::from([
                 Field {
@@ -283,11 +467,15 @@ fn display_type_alias() {
                         }]),
                     }),
                     optional: false,
+                    readonly: false,
+                    docs: Vec::new(),
                 },
                 Field {
                     name: String::from("n"),
                     ty: Type::Builtin(BuiltinType::Number),
                     optional: false,
+                    readonly: false,
+                    docs: Vec::new(),
                 },
             ]),
         }),
@@ -310,13 +498,16 @@ fn display_interface() {
 
     let def = Interface {
         export: true,
+        docs: Vec::new(),
         name: String::from("Foo"),
-        parameters: Vec::from(["K"]),
+        parameters: Vec::from([TypeParameter::from("K")]),
         fields: vec![
             Field {
                 name: String::from("foo"),
                 ty: Type::Builtin(BuiltinType::Number),
                 optional: false,
+                readonly: false,
+                docs: Vec::new(),
             },
             Field {
                 name: String::from("bar"),
@@ -327,6 +518,8 @@ fn display_interface() {
                     }]),
                 }),
                 optional: false,
+                readonly: false,
+                docs: Vec::new(),
             },
         ],
     };
@@ -351,8 +544,9 @@ fn display_enum() {
 
     let def = TypeAlias {
         export: true,
+        docs: Vec::new(),
         name: String::from("Foo"),
-        parameters: Vec::from(["T"]),
+        parameters: Vec::from([TypeParameter::from("T")]),
         ty: Type::Union(UnionType {
             variants: Vec::from([
                 Variant(Type::Builtin(BuiltinType::Number)),
@@ -384,7 +578,7 @@ fn display_enum() {
 #[test]
 fn serde_container_rename_struct() {
     #[derive(Camo, Serialize, Deserialize)]
-    #[serde(rename = "camelCase")]
+    #[serde(rename = "foo_bar_v2")]
     struct FooBar {
         one_two_three: i32,
         four_five_six: Vec<u8>,
@@ -396,18 +590,23 @@ fn serde_container_rename_struct() {
         def,
         Definition::Interface(Interface {
             export: false,
-            name: String::from("fooBar"),
+            docs: Vec::new(),
+            name: String::from("foo_bar_v2"),
             parameters: Vec::new(),
             fields: Vec::from([
                 Field {
                     name: String::from("one_two_three"),
                     ty: Type::Builtin(BuiltinType::Number),
                     optional: false,
+                    readonly: false,
+                    docs: Vec::new(),
                 },
                 Field {
                     name: String::from("four_five_six"),
                     ty: Type::Array(ArrayType::from(Type::Builtin(BuiltinType::Number))),
                     optional: false,
+                    readonly: false,
+                    docs: Vec::new(),
                 },
             ]),
         })
@@ -417,7 +616,7 @@ fn serde_container_rename_struct() {
 #[test]
 fn serde_container_rename_enum() {
     #[derive(Camo, Serialize, Deserialize)]
-    #[serde(rename = "camelCase")]
+    #[serde(rename = "Foo")]
     enum FooBar {
         VariantOne(i32),
         VariantTwo { value: String },
@@ -427,7 +626,8 @@ fn serde_container_rename_enum() {
         Definition::from(FooBar::camo()),
         Definition::Alias(TypeAlias {
             export: false,
-            name: String::from("fooBar"),
+            docs: Vec::new(),
+            name: String::from("Foo"),
             parameters: Vec::new(),
             ty: Type::Union(UnionType {
                 variants: Vec::from([
@@ -436,6 +636,8 @@ fn serde_container_rename_enum() {
                             name: String::from("VariantOne"),
                             ty: Type::Builtin(BuiltinType::Number),
                             optional: false,
+                            readonly: false,
+                            docs: Vec::new(),
                         }])
                     })),
                     Variant(Type::Object(ObjectType {
@@ -446,9 +648,13 @@ fn serde_container_rename_enum() {
                                     name: String::from("value"),
                                     ty: Type::Builtin(BuiltinType::String),
                                     optional: false,
+                                    readonly: false,
+                                    docs: Vec::new(),
                                 }])
                             }),
                             optional: false,
+                            readonly: false,
+                            docs: Vec::new(),
                         }])
                     })),
                 ]),
@@ -472,6 +678,7 @@ fn serde_container_rename_all_struct() {
         def,
         Definition::Interface(Interface {
             export: false,
+            docs: Vec::new(),
             name: String::from("Foo"),
             parameters: Vec::new(),
             fields: Vec::from([
@@ -479,11 +686,15 @@ fn serde_container_rename_all_struct() {
                     name: String::from("oneTwoThree"),
                     ty: Type::Builtin(BuiltinType::Number),
                     optional: false,
+                    readonly: false,
+                    docs: Vec::new(),
                 },
                 Field {
                     name: String::from("fourFiveSix"),
                     ty: Type::Array(ArrayType::from(Type::Builtin(BuiltinType::Number))),
                     optional: false,
+                    readonly: false,
+                    docs: Vec::new(),
                 },
             ]),
         })
@@ -503,6 +714,7 @@ fn serde_container_rename_all_enum() {
         Definition::from(FooBar::camo()),
         Definition::Alias(TypeAlias {
             export: false,
+            docs: Vec::new(),
             name: String::from("FooBar"),
             parameters: Vec::new(),
             ty: Type::Union(UnionType {
@@ -512,6 +724,8 @@ fn serde_container_rename_all_enum() {
                             name: String::from("variantOne"),
                             ty: Type::Builtin(BuiltinType::Number),
                             optional: false,
+                            readonly: false,
+                            docs: Vec::new(),
                         }])
                     })),
                     Variant(Type::Object(ObjectType {
@@ -522,9 +736,13 @@ fn serde_container_rename_all_enum() {
                                     name: String::from("value"),
                                     ty: Type::Builtin(BuiltinType::String),
                                     optional: false,
+                                    readonly: false,
+                                    docs: Vec::new(),
                                 }])
                             }),
                             optional: false,
+                            readonly: false,
+                            docs: Vec::new(),
                         }])
                     })),
                 ]),
@@ -533,12 +751,208 @@ fn serde_container_rename_all_enum() {
     )
 }
 
+#[test]
+fn serde_field_rename() {
+    #[derive(Camo, Serialize, Deserialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Foo {
+        #[serde(rename = "ID")]
+        id: u64,
+        display_name: String,
+    }
+
+    let def: Definition = Foo::camo().into();
+
+    assert_eq!(
+        def,
+        Definition::Interface(Interface {
+            export: false,
+            docs: Vec::new(),
+            name: String::from("Foo"),
+            parameters: Vec::new(),
+            fields: Vec::from([
+                Field {
+                    name: String::from("ID"),
+                    ty: Type::Builtin(BuiltinType::Number),
+                    optional: false,
+                    readonly: false,
+                    docs: Vec::new(),
+                },
+                Field {
+                    name: String::from("displayName"),
+                    ty: Type::Builtin(BuiltinType::String),
+                    optional: false,
+                    readonly: false,
+                    docs: Vec::new(),
+                },
+            ]),
+        })
+    );
+}
+
+#[test]
+fn serde_field_skip_and_optional() {
+    use camo::core::Direction;
+
+    #[derive(Camo, Serialize, Deserialize)]
+    struct Foo {
+        #[serde(skip)]
+        cache: u32,
+        #[serde(skip_serializing, skip_deserializing)]
+        also_skipped: u32,
+        #[serde(default)]
+        defaulted: u32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        maybe: Option<u32>,
+        #[serde(skip_serializing)]
+        secret: String,
+        #[serde(skip_deserializing)]
+        computed: bool,
+    }
+
+    let maybe = Type::Union(UnionType {
+        variants: Vec::from([
+            Variant(Type::Builtin(BuiltinType::Number)),
+            Variant(Type::Builtin(BuiltinType::Null)),
+        ]),
+    });
+
+    assert_eq!(
+        Definition::from_container(Foo::camo(), Direction::Serialize),
+        Definition::Interface(Interface {
+            export: false,
+            docs: Vec::new(),
+            name: String::from("Foo"),
+            parameters: Vec::new(),
+            fields: Vec::from([
+                Field {
+                    name: String::from("defaulted"),
+                    ty: Type::Builtin(BuiltinType::Number),
+                    optional: false,
+                    readonly: false,
+                    docs: Vec::new(),
+                },
+                Field {
+                    name: String::from("maybe"),
+                    ty: maybe.clone(),
+                    optional: true,
+                    readonly: false,
+                    docs: Vec::new(),
+                },
+                Field {
+                    name: String::from("computed"),
+                    ty: Type::Builtin(BuiltinType::Boolean),
+                    optional: false,
+                    readonly: false,
+                    docs: Vec::new(),
+                },
+            ]),
+        })
+    );
+
+    assert_eq!(
+        Definition::from_container(Foo::camo(), Direction::Deserialize),
+        Definition::Interface(Interface {
+            export: false,
+            docs: Vec::new(),
+            name: String::from("Foo"),
+            parameters: Vec::new(),
+            fields: Vec::from([
+                Field {
+                    name: String::from("defaulted"),
+                    ty: Type::Builtin(BuiltinType::Number),
+                    optional: true,
+                    readonly: false,
+                    docs: Vec::new(),
+                },
+                Field {
+                    name: String::from("maybe"),
+                    ty: maybe,
+                    optional: false,
+                    readonly: false,
+                    docs: Vec::new(),
+                },
+                Field {
+                    name: String::from("secret"),
+                    ty: Type::Builtin(BuiltinType::String),
+                    optional: false,
+                    readonly: false,
+                    docs: Vec::new(),
+                },
+            ]),
+        })
+    );
+}
+
+#[test]
+fn serde_unnamed_field_skip() {
+    use camo::core::Direction;
+
+    #[derive(Camo, Serialize, Deserialize)]
+    struct Pair(#[serde(skip)] u32, String);
+
+    assert_eq!(
+        Definition::from(Pair::camo()).to_string(),
+        "type Pair = [string];\n"
+    );
+
+    #[derive(Camo, Serialize, Deserialize)]
+    enum Message {
+        Write(#[serde(skip_deserializing)] u32, bool),
+    }
+
+    assert_eq!(
+        Definition::from_container(Message::camo(), Direction::Serialize).to_string(),
+        "type Message =\n\t| { Write: [number, boolean]; };\n"
+    );
+    assert_eq!(
+        Definition::from_container(Message::camo(), Direction::Deserialize).to_string(),
+        "type Message =\n\t| { Write: [boolean]; };\n"
+    );
+
+    // `serde` ignores `skip` on the field of a newtype
+    #[derive(Camo, Serialize)]
+    struct Id(#[serde(skip)] u64);
+
+    assert_eq!(
+        Definition::from(Id::camo()).to_string(),
+        "type Id = number;\n"
+    );
+}
+
+#[test]
+fn serde_variant_field_rename() {
+    use unindent::Unindent;
+
+    #[derive(Camo, Serialize, Deserialize)]
+    #[serde(tag = "type")]
+    enum Event {
+        #[serde(rename_all = "camelCase")]
+        Created {
+            #[serde(rename = "userID")]
+            user_id: u64,
+            created_at: String,
+        },
+    }
+
+    let def: Definition = Event::camo().into();
+
+    assert_eq!(
+        def.to_string(),
+        "
+        type Event =
+        \t| { type: \"Created\"; } & { userID: number; createdAt: string; };
+        "
+        .unindent()
+    );
+}
+
 #[test]
 fn serde_variant_rename() {
     #[derive(Camo, Serialize, Deserialize)]
     #[serde(rename_all = "camelCase")]
     enum FooBar {
-        #[serde(rename = "UPPERCASE")]
+        #[serde(rename = "variant-1")]
         VariantOne(i32),
         VariantTwo {
             value: String,
@@ -549,15 +963,18 @@ fn serde_variant_rename() {
         Definition::from(FooBar::camo()),
         Definition::Alias(TypeAlias {
             export: false,
+            docs: Vec::new(),
             name: String::from("FooBar"),
             parameters: Vec::new(),
             ty: Type::Union(UnionType {
                 variants: Vec::from([
                     Variant(Type::Object(ObjectType {
                         fields: Vec::from([Field {
-                            name: String::from("VARIANTONE"),
+                            name: String::from("variant-1"),
                             ty: Type::Builtin(BuiltinType::Number),
                             optional: false,
+                            readonly: false,
+                            docs: Vec::new(),
                         }])
                     })),
                     Variant(Type::Object(ObjectType {
@@ -568,9 +985,13 @@ fn serde_variant_rename() {
                                     name: String::from("value"),
                                     ty: Type::Builtin(BuiltinType::String),
                                     optional: false,
+                                    readonly: false,
+                                    docs: Vec::new(),
                                 }])
                             }),
                             optional: false,
+                            readonly: false,
+                            docs: Vec::new(),
                         }]),
                     })),
                 ]),
@@ -595,6 +1016,7 @@ fn serde_variant_rename_all() {
         Definition::from(FooBar::camo()),
         Definition::Alias(TypeAlias {
             export: false,
+            docs: Vec::new(),
             name: String::from("FooBar"),
             parameters: Vec::new(),
             ty: Type::Union(UnionType {
@@ -604,6 +1026,8 @@ fn serde_variant_rename_all() {
                             name: String::from("variantOne"),
                             ty: Type::Builtin(BuiltinType::Number),
                             optional: false,
+                            readonly: false,
+                            docs: Vec::new(),
                         }])
                     })),
                     Variant(Type::Object(ObjectType {
@@ -614,9 +1038,13 @@ fn serde_variant_rename_all() {
                                     name: String::from("VALUE"),
                                     ty: Type::Builtin(BuiltinType::String),
                                     optional: false,
+                                    readonly: false,
+                                    docs: Vec::new(),
                                 }])
                             }),
                             optional: false,
+                            readonly: false,
+                            docs: Vec::new(),
                         }])
                     })),
                 ]),
@@ -644,8 +1072,9 @@ fn enum_externally_tagged() {
         def,
         Definition::Alias(TypeAlias {
             export: false,
+            docs: Vec::new(),
             name: String::from("Foo"),
-            parameters: Vec::from(["T"]),
+            parameters: Vec::from([TypeParameter::from("T")]),
             ty: Type::Union(UnionType {
                 variants: Vec::from([
                     Variant(Type::Literal(LiteralType::String(String::from("Zero")))),
@@ -654,6 +1083,8 @@ fn enum_externally_tagged() {
                             name: String::from("One"),
                             ty: Type::Builtin(BuiltinType::Boolean),
                             optional: false,
+                            readonly: false,
+                            docs: Vec::new(),
                         }])
                     })),
                     Variant(Type::Object(ObjectType {
@@ -666,6 +1097,8 @@ fn enum_externally_tagged() {
                                 }])
                             }),
                             optional: false,
+                            readonly: false,
+                            docs: Vec::new(),
                         }])
                     })),
                     Variant(Type::Object(ObjectType {
@@ -678,6 +1111,8 @@ fn enum_externally_tagged() {
                                 }])
                             }),
                             optional: false,
+                            readonly: false,
+                            docs: Vec::new(),
                         }])
                     })),
                     Variant(Type::Object(ObjectType {
@@ -690,9 +1125,13 @@ fn enum_externally_tagged() {
                                         BuiltinType::Number
                                     ))),
                                     optional: false,
+                                    readonly: false,
+                                    docs: Vec::new(),
                                 }])
                             }),
                             optional: false,
+                            readonly: false,
+                            docs: Vec::new(),
                         }])
                     })),
                 ]),
@@ -719,6 +1158,7 @@ fn enum_internally_tagged() {
         def,
         Definition::Alias(TypeAlias {
             export: false,
+            docs: Vec::new(),
             name: String::from("Foo"),
             parameters: Vec::new(),
             ty: Type::Union(UnionType {
@@ -729,6 +1169,8 @@ fn enum_internally_tagged() {
                                 name: String::from("tag"),
                                 ty: Type::Literal(LiteralType::String(String::from("VariantOne"))),
                                 optional: false,
+                                readonly: false,
+                                docs: Vec::new(),
                             },])
                         })),
                         right: Box::new(Type::Path(TypePath {
@@ -744,6 +1186,8 @@ fn enum_internally_tagged() {
                                 name: String::from("tag"),
                                 ty: Type::Literal(LiteralType::String(String::from("VariantTwo"))),
                                 optional: false,
+                                readonly: false,
+                                docs: Vec::new(),
                             },])
                         })),
                         right: Box::new(Type::Object(ObjectType {
@@ -756,6 +1200,8 @@ fn enum_internally_tagged() {
                                     },])
                                 }),
                                 optional: false,
+                                readonly: false,
+                                docs: Vec::new(),
                             }])
                         }))
                     })),
@@ -765,6 +1211,70 @@ fn enum_internally_tagged() {
     );
 }
 
+#[test]
+fn enum_internally_tagged_unit_content() {
+    #[derive(Camo, Serialize, Deserialize)]
+    #[serde(tag = "tag")]
+    enum Foo {
+        Ping(()),
+    }
+
+    let def: Definition = Foo::camo().into();
+
+    assert_eq!(
+        def,
+        Definition::Alias(TypeAlias {
+            export: false,
+            docs: Vec::new(),
+            name: String::from("Foo"),
+            parameters: Vec::new(),
+            ty: Type::Union(UnionType {
+                variants: Vec::from([Variant(Type::Object(ObjectType {
+                    fields: Vec::from([Field {
+                        name: String::from("tag"),
+                        ty: Type::Literal(LiteralType::String(String::from("Ping"))),
+                        optional: false,
+                        readonly: false,
+                        docs: Vec::new(),
+                    }])
+                }))])
+            })
+        })
+    );
+}
+
+#[test]
+fn enum_internally_tagged_unit_struct_content() {
+    use unindent::Unindent;
+
+    #[derive(Camo, Serialize, Deserialize)]
+    struct Ping;
+
+    #[derive(Camo, Serialize, Deserialize)]
+    struct Pong {
+        count: u32,
+    }
+
+    #[derive(Camo, Serialize, Deserialize)]
+    #[serde(tag = "type")]
+    enum Msg {
+        Ping(Ping),
+        Pong(Pong),
+    }
+
+    let def: Definition = Msg::camo().into();
+
+    assert_eq!(
+        def.to_string(),
+        "
+        type Msg =
+        \t| { type: \"Ping\"; }
+        \t| { type: \"Pong\"; } & Pong;
+        "
+        .unindent()
+    );
+}
+
 #[test]
 fn enum_adjacently_tagged() {
     #[derive(Camo, Serialize, Deserialize)]
@@ -780,6 +1290,7 @@ fn enum_adjacently_tagged() {
         def,
         Definition::Alias(TypeAlias {
             export: false,
+            docs: Vec::new(),
             name: String::from("Foo"),
             parameters: Vec::new(),
             ty: Type::Union(UnionType {
@@ -790,11 +1301,15 @@ fn enum_adjacently_tagged() {
                                 name: String::from("tag"),
                                 ty: Type::Literal(LiteralType::String(String::from("VariantOne"))),
                                 optional: false,
+                                readonly: false,
+                                docs: Vec::new(),
                             },
                             Field {
                                 name: String::from("content"),
                                 ty: Type::Builtin(BuiltinType::Number),
                                 optional: false,
+                                readonly: false,
+                                docs: Vec::new(),
                             },
                         ])
                     })),
@@ -804,6 +1319,8 @@ fn enum_adjacently_tagged() {
                                 name: String::from("tag"),
                                 ty: Type::Literal(LiteralType::String(String::from("VariantTwo"))),
                                 optional: false,
+                                readonly: false,
+                                docs: Vec::new(),
                             },
                             Field {
                                 name: String::from("content"),
@@ -812,9 +1329,13 @@ fn enum_adjacently_tagged() {
                                         name: String::from("valid"),
                                         ty: Type::Builtin(BuiltinType::Boolean),
                                         optional: false,
+                                        readonly: false,
+                                        docs: Vec::new(),
                                     }])
                                 }),
                                 optional: false,
+                                readonly: false,
+                                docs: Vec::new(),
                             },
                         ])
                     })),
@@ -823,3 +1344,1596 @@ fn enum_adjacently_tagged() {
         })
     );
 }
+
+#[test]
+fn enum_tuple_variants() {
+    #[derive(Camo, Serialize, Deserialize)]
+    enum Foo {
+        Move(u32, bool),
+    }
+
+    #[derive(Camo, Serialize, Deserialize)]
+    #[serde(tag = "tag", content = "content")]
+    enum Bar {
+        Move(u32, bool),
+    }
+
+    let content = Type::Tuple(TupleType(Vec::from([
+        Type::Builtin(BuiltinType::Number),
+        Type::Builtin(BuiltinType::Boolean),
+    ])));
+
+    assert_eq!(
+        Definition::from(Foo::camo()),
+        Definition::Alias(TypeAlias {
+            export: false,
+            docs: Vec::new(),
+            name: String::from("Foo"),
+            parameters: Vec::new(),
+            ty: Type::Union(UnionType {
+                variants: Vec::from([Variant(Type::Object(ObjectType {
+                    fields: Vec::from([Field {
+                        name: String::from("Move"),
+                        ty: content.clone(),
+                        optional: false,
+                        readonly: false,
+                        docs: Vec::new(),
+                    }])
+                }))])
+            })
+        })
+    );
+
+    assert_eq!(
+        Definition::from(Bar::camo()),
+        Definition::Alias(TypeAlias {
+            export: false,
+            docs: Vec::new(),
+            name: String::from("Bar"),
+            parameters: Vec::new(),
+            ty: Type::Union(UnionType {
+                variants: Vec::from([Variant(Type::Object(ObjectType {
+                    fields: Vec::from([
+                        Field {
+                            name: String::from("tag"),
+                            ty: Type::Literal(LiteralType::String(String::from("Move"))),
+                            optional: false,
+                            readonly: false,
+                            docs: Vec::new(),
+                        },
+                        Field {
+                            name: String::from("content"),
+                            ty: content,
+                            optional: false,
+                            readonly: false,
+                            docs: Vec::new(),
+                        },
+                    ])
+                }))])
+            })
+        })
+    );
+}
+
+#[test]
+fn enum_internally_tagged_tuple_variant() {
+    use camo::core::{
+        BuiltinType as RustType, Container, ContainerAttributes, Docs, Enum, Item,
+        Type as RustTypeUse, UnnamedField, VariantAttributes, VariantContent, Visibility,
+    };
+
+    // The derive macro rejects such a variant, as `serde` does
+    let variant = |name, content| camo::core::Variant {
+        attributes: VariantAttributes::default(),
+        docs: Docs::default(),
+        name,
+        discriminant: None,
+        content,
+    };
+    let container = Container {
+        module_path: module_path!(),
+        attributes: ContainerAttributes {
+            tag: Some("tag"),
+            ..ContainerAttributes::default()
+        },
+        docs: Docs::default(),
+        item: Item::Enum(Enum {
+            visibility: Visibility::None,
+            name: "Foo",
+            parameters: Vec::new(),
+            variants: Vec::from([
+                variant(
+                    "Move",
+                    VariantContent::Unnamed(Vec::from([
+                        UnnamedField::from(RustTypeUse::Builtin(RustType::U32)),
+                        UnnamedField::from(RustTypeUse::Builtin(RustType::Bool)),
+                    ])),
+                ),
+                variant("Stop", VariantContent::Unit),
+            ]),
+        }),
+    };
+
+    let diagnostics = camo_typescript::diagnostics(&container);
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].to_string(),
+        "`Foo`: variant `Move` is a tuple variant, which `serde` cannot serialize \
+        in an internally tagged enum, so it is described as `never`"
+    );
+    assert_eq!(
+        Definition::from(container).to_string(),
+        "type Foo =\n\t| never\n\t| { tag: \"Stop\"; };\n"
+    );
+}
+
+#[test]
+fn enum_repr() {
+    use unindent::Unindent;
+
+    #[derive(Camo)]
+    #[camo(repr)]
+    pub enum Status {
+        Active = 1,
+        Disabled,
+    }
+
+    #[derive(Camo)]
+    pub enum Plain {
+        Active = 1,
+        Disabled,
+    }
+
+    let def: Definition = Status::camo().into();
+
+    assert_eq!(
+        def,
+        Definition::Alias(TypeAlias {
+            export: true,
+            docs: Vec::new(),
+            name: String::from("Status"),
+            parameters: Vec::new(),
+            ty: Type::Union(UnionType {
+                variants: Vec::from([
+                    Variant(Type::Literal(LiteralType::Number(1))),
+                    Variant(Type::Literal(LiteralType::Number(2))),
+                ])
+            })
+        })
+    );
+
+    assert_eq!(
+        def.to_string(),
+        "
+        export type Status =
+        \t| 1
+        \t| 2;
+        "
+        .unindent()
+    );
+
+    let def: Definition = Plain::camo().into();
+
+    assert_eq!(
+        def.to_string(),
+        "
+        export type Plain =
+        \t| \"Active\"
+        \t| \"Disabled\";
+        "
+        .unindent()
+    );
+}
+
+#[test]
+fn serde_flatten_struct() {
+    use std::collections::HashMap;
+
+    #[derive(Camo, Serialize, Deserialize)]
+    struct Metadata {
+        created_at: String,
+    }
+
+    #[derive(Camo, Serialize, Deserialize)]
+    struct User {
+        #[serde(flatten)]
+        metadata: Metadata,
+        name: String,
+        #[serde(flatten)]
+        extra: HashMap<String, u32>,
+    }
+
+    let def: Definition = User::camo().into();
+
+    assert_eq!(
+        def,
+        Definition::Alias(TypeAlias {
+            export: false,
+            docs: Vec::new(),
+            name: String::from("User"),
+            parameters: Vec::new(),
+            ty: Type::Intersection(IntersectionType {
+                left: Box::new(Type::Intersection(IntersectionType {
+                    left: Box::new(Type::Path(TypePath::from("Metadata"))),
+                    right: Box::new(Type::Path(TypePath {
+                        segments: Vec::from([PathSegment {
+                            name: String::from("Record"),
+                            arguments: Vec::from([
+                                Type::Builtin(BuiltinType::String),
+                                Type::Builtin(BuiltinType::Number),
+                            ]),
+                        }]),
+                    })),
+                })),
+                right: Box::new(Type::Object(ObjectType {
+                    fields: Vec::from([Field {
+                        name: String::from("name"),
+                        ty: Type::Builtin(BuiltinType::String),
+                        optional: false,
+                        readonly: false,
+                        docs: Vec::new(),
+                    }]),
+                })),
+            }),
+        })
+    );
+
+    assert_eq!(
+        def.to_string(),
+        "type User = Metadata & Record<string, number> & { name: string; };\n"
+    );
+}
+
+#[test]
+fn serde_flatten_only() {
+    #[derive(Camo, Serialize, Deserialize)]
+    struct Metadata {
+        created_at: String,
+    }
+
+    #[derive(Camo, Serialize, Deserialize)]
+    struct Wrapper {
+        #[serde(flatten)]
+        metadata: Metadata,
+    }
+
+    let def: Definition = Wrapper::camo().into();
+
+    assert_eq!(def.to_string(), "type Wrapper = Metadata;\n");
+}
+
+#[test]
+fn serde_flatten_optional() {
+    #[derive(Camo, Serialize, Deserialize)]
+    struct Meta {
+        created_at: String,
+    }
+
+    #[derive(Camo, Serialize, Deserialize)]
+    struct Record {
+        #[serde(flatten)]
+        meta: Option<Meta>,
+        id: u32,
+    }
+
+    let def: Definition = Record::camo().into();
+
+    assert_eq!(
+        def.to_string(),
+        "type Record = Partial<Meta> & { id: number; };\n"
+    );
+}
+
+#[test]
+fn intersection_with_union() {
+    let ty = Type::Intersection(IntersectionType {
+        left: Box::new(Type::Union(UnionType {
+            variants: Vec::from([
+                Variant(Type::Path(TypePath::from("Meta"))),
+                Variant(Type::Builtin(BuiltinType::Null)),
+            ]),
+        })),
+        right: Box::new(Type::Path(TypePath::from("Base"))),
+    });
+
+    assert_eq!(ty.to_string(), "(\n\t| Meta\n\t| null) & Base");
+}
+
+#[test]
+fn serde_flatten_variant() {
+    use unindent::Unindent;
+
+    #[derive(Camo, Serialize, Deserialize)]
+    struct Metadata {
+        created_at: String,
+    }
+
+    #[derive(Camo, Serialize, Deserialize)]
+    enum External {
+        Created {
+            #[serde(flatten)]
+            metadata: Metadata,
+            id: u32,
+        },
+    }
+
+    #[derive(Camo, Serialize, Deserialize)]
+    #[serde(tag = "type", content = "data")]
+    enum Adjacent {
+        Created {
+            #[serde(flatten)]
+            metadata: Metadata,
+            id: u32,
+        },
+    }
+
+    #[derive(Camo, Serialize, Deserialize)]
+    #[serde(tag = "type")]
+    enum Internal {
+        Created {
+            #[serde(flatten)]
+            metadata: Metadata,
+            id: u32,
+        },
+    }
+
+    let def: Definition = External::camo().into();
+
+    assert_eq!(
+        def.to_string(),
+        "
+        type External =
+        \t| { Created: Metadata & { id: number; }; };
+        "
+        .unindent()
+    );
+
+    let def: Definition = Adjacent::camo().into();
+
+    assert_eq!(
+        def.to_string(),
+        "
+        type Adjacent =
+        \t| { type: \"Created\"; data: Metadata & { id: number; }; };
+        "
+        .unindent()
+    );
+
+    let def: Definition = Internal::camo().into();
+
+    assert_eq!(
+        def.to_string(),
+        "
+        type Internal =
+        \t| { type: \"Created\"; } & Metadata & { id: number; };
+        "
+        .unindent()
+    );
+}
+
+#[test]
+fn serde_untagged() {
+    #[derive(Camo, Serialize, Deserialize)]
+    #[serde(untagged)]
+    enum Value {
+        Empty,
+        Number(i32),
+        Pair(i32, i32),
+        Named { text: String },
+    }
+
+    let def: Definition = Value::camo().into();
+
+    assert_eq!(
+        def,
+        Definition::Alias(TypeAlias {
+            export: false,
+            docs: Vec::new(),
+            name: String::from("Value"),
+            parameters: Vec::new(),
+            ty: Type::Union(UnionType {
+                variants: Vec::from([
+                    Variant(Type::Builtin(BuiltinType::Null)),
+                    Variant(Type::Builtin(BuiltinType::Number)),
+                    Variant(Type::Tuple(TupleType(Vec::from([
+                        Type::Builtin(BuiltinType::Number),
+                        Type::Builtin(BuiltinType::Number),
+                    ])))),
+                    Variant(Type::Object(ObjectType {
+                        fields: Vec::from([Field {
+                            name: String::from("text"),
+                            ty: Type::Builtin(BuiltinType::String),
+                            optional: false,
+                            readonly: false,
+                            docs: Vec::new(),
+                        }]),
+                    })),
+                ]),
+            }),
+        })
+    );
+}
+
+#[test]
+fn serde_untagged_variant() {
+    use unindent::Unindent;
+
+    #[derive(Camo, Serialize, Deserialize)]
+    #[serde(tag = "type")]
+    enum Event {
+        Ping,
+        #[serde(untagged)]
+        Other(String),
+    }
+
+    let def: Definition = Event::camo().into();
+
+    assert_eq!(
+        def.to_string(),
+        "
+        type Event =
+        \t| { type: \"Ping\"; }
+        \t| string;
+        "
+        .unindent()
+    );
+}
+
+#[test]
+fn serde_transparent() {
+    #[derive(Camo, Serialize, Deserialize)]
+    #[serde(transparent)]
+    pub struct UserId(u64);
+
+    #[derive(Camo, Serialize, Deserialize)]
+    #[serde(transparent)]
+    struct Wrapper<T> {
+        value: T,
+    }
+
+    let def: Definition = UserId::camo().into();
+
+    assert_eq!(
+        def,
+        Definition::Alias(TypeAlias {
+            export: true,
+            docs: Vec::new(),
+            name: String::from("UserId"),
+            parameters: Vec::new(),
+            ty: Type::Builtin(BuiltinType::Number),
+        })
+    );
+
+    let def: Definition = Wrapper::<()>::camo().into();
+
+    assert_eq!(def.to_string(), "type Wrapper<T> = T;\n");
+}
+
+#[test]
+fn serde_into() {
+    #[derive(Camo, Clone, Serialize, Deserialize)]
+    #[serde(into = "String", from = "String")]
+    struct Email {
+        user: String,
+        domain: String,
+    }
+
+    impl From<Email> for String {
+        fn from(value: Email) -> Self {
+            format!("{}@{}", value.user, value.domain)
+        }
+    }
+
+    impl From<String> for Email {
+        fn from(value: String) -> Self {
+            let (user, domain) = value.split_once('@').unwrap_or_default();
+            Email {
+                user: user.to_string(),
+                domain: domain.to_string(),
+            }
+        }
+    }
+
+    let def: Definition = Email::camo().into();
+
+    assert_eq!(
+        def,
+        Definition::Alias(TypeAlias {
+            export: false,
+            docs: Vec::new(),
+            name: String::from("Email"),
+            parameters: Vec::new(),
+            ty: Type::Builtin(BuiltinType::String),
+        })
+    );
+}
+
+#[test]
+fn serde_direction_rename() {
+    use camo::core::Direction;
+    use unindent::Unindent;
+
+    #[derive(Camo, Serialize, Deserialize)]
+    #[serde(
+        rename(serialize = "UserOutput", deserialize = "UserInput"),
+        rename_all(serialize = "camelCase")
+    )]
+    struct User {
+        user_name: String,
+        #[serde(rename(deserialize = "pw"), alias = "pass")]
+        password: String,
+    }
+
+    assert_eq!(
+        Definition::from(User::camo()).to_string(),
+        "
+        interface UserOutput {
+        \tuserName: string;
+        \tpassword: string;
+        }
+        "
+        .unindent()
+    );
+
+    assert_eq!(
+        Definition::from_container(User::camo(), Direction::Deserialize).to_string(),
+        "
+        interface UserInput {
+        \tuser_name: string;
+        \tpw: string;
+        }
+        "
+        .unindent()
+    );
+}
+
+#[test]
+fn serde_direction_container() {
+    use camo::core::Direction;
+
+    #[derive(Camo, Clone, Serialize, Deserialize)]
+    #[serde(into = "String", from = "u64")]
+    struct Id {
+        value: u64,
+    }
+
+    impl From<Id> for String {
+        fn from(value: Id) -> Self {
+            value.value.to_string()
+        }
+    }
+
+    impl From<u64> for Id {
+        fn from(value: u64) -> Self {
+            Id { value }
+        }
+    }
+
+    #[derive(Camo, Serialize, Deserialize, Default)]
+    #[serde(default)]
+    struct Options {
+        verbose: bool,
+    }
+
+    assert_eq!(
+        Definition::from_container(Id::camo(), Direction::Serialize).to_string(),
+        "type Id = string;\n"
+    );
+    assert_eq!(
+        Definition::from_container(Id::camo(), Direction::Deserialize).to_string(),
+        "type Id = number;\n"
+    );
+    assert_eq!(
+        Definition::from_container(Options::camo(), Direction::Serialize).to_string(),
+        "interface Options {\n\tverbose: boolean;\n}\n"
+    );
+    assert_eq!(
+        Definition::from_container(Options::camo(), Direction::Deserialize).to_string(),
+        "interface Options {\n\tverbose?: boolean;\n}\n"
+    );
+}
+
+#[test]
+fn serde_direction_variants() {
+    use camo::core::Direction;
+    use unindent::Unindent;
+
+    #[derive(Camo, Serialize, Deserialize)]
+    enum Command {
+        #[serde(rename(serialize = "started"))]
+        Start,
+        #[serde(skip_deserializing)]
+        Internal,
+        #[serde(skip_serializing)]
+        Reset,
+    }
+
+    assert_eq!(
+        Definition::from_container(Command::camo(), Direction::Serialize).to_string(),
+        "
+        type Command =
+        \t| \"started\"
+        \t| \"Internal\";
+        "
+        .unindent()
+    );
+
+    assert_eq!(
+        Definition::from_container(Command::camo(), Direction::Deserialize)
+            .renamed("CommandInput")
+            .to_string(),
+        "
+        type CommandInput =
+        \t| \"Start\"
+        \t| \"Reset\";
+        "
+        .unindent()
+    );
+}
+
+#[test]
+fn std_types() {
+    use std::borrow::Cow;
+    use std::collections::{BTreeSet, HashMap, VecDeque};
+    use std::net::IpAddr;
+    use std::num::NonZeroU64;
+    use std::path::PathBuf;
+    use std::sync::Arc;
+    use unindent::Unindent;
+
+    #[derive(Camo)]
+    struct Foo<'a> {
+        name: Cow<'a, str>,
+        tags: BTreeSet<String>,
+        queue: VecDeque<Arc<u8>>,
+        scores: HashMap<String, Option<f64>>,
+        path: PathBuf,
+        address: IpAddr,
+        count: NonZeroU64,
+        next: Option<Box<Foo<'a>>>,
+    }
+
+    assert_eq!(
+        Definition::from(Foo::camo()).to_string(),
+        "
+        interface Foo {
+        \tname: string;
+        \ttags: string[];
+        \tqueue: number[];
+        \tscores: Record<string, (
+        \t| number
+        \t| null)>;
+        \tpath: string;
+        \taddress: string;
+        \tcount: number;
+        \tnext: 
+        \t| Foo
+        \t| null;
+        }
+        "
+        .unindent()
+    );
+}
+
+#[test]
+fn std_types_by_name_only() {
+    use unindent::Unindent;
+
+    mod custom {
+        pub struct Vec<T>(pub T);
+        pub struct Option;
+    }
+
+    #[derive(Camo)]
+    struct Foo {
+        list: custom::Vec<u8>,
+        option: custom::Option,
+    }
+
+    assert_eq!(
+        Definition::from(Foo::camo()).to_string(),
+        "
+        interface Foo {
+        \tlist: custom.Vec<number>;
+        \toption: custom.Option;
+        }
+        "
+        .unindent()
+    );
+}
+
+#[test]
+fn std_types_as_definitions() {
+    use camo::core::TypeRegistry;
+    use std::time::Duration;
+    use unindent::Unindent;
+
+    #[derive(Camo)]
+    struct Job {
+        timeout: Duration,
+        outcome: Result<u32, String>,
+    }
+
+    let mut registry = TypeRegistry::new();
+    registry.add::<Job>();
+
+    let names: Vec<_> = registry
+        .containers()
+        .into_iter()
+        .map(|container| container.item.name())
+        .collect();
+    assert_eq!(names, ["Duration", "Result", "Job"]);
+
+    let duration = registry.get(&Duration::identity()).unwrap().clone();
+    assert_eq!(
+        Definition::from(duration).to_string(),
+        "
+        export interface Duration {
+        \tsecs: number;
+        \tnanos: number;
+        }
+        "
+        .unindent()
+    );
+
+    assert_eq!(
+        Definition::from(Job::camo()).to_string(),
+        "
+        interface Job {
+        \ttimeout: Duration;
+        \toutcome: Result<number, string>;
+        }
+        "
+        .unindent()
+    );
+}
+
+#[test]
+fn map_keys() {
+    use camo_typescript::diagnostics;
+    use std::collections::{BTreeMap, HashMap};
+    use unindent::Unindent;
+
+    #[derive(Camo)]
+    enum Kind {
+        Small,
+        Large,
+    }
+
+    #[derive(Camo)]
+    struct Foo {
+        names: HashMap<String, u32>,
+        ids: BTreeMap<u64, bool>,
+        kinds: HashMap<Kind, f32>,
+        cells: BTreeMap<(u8, u8), char>,
+    }
+
+    #[derive(Camo)]
+    struct Skipped {
+        #[serde(skip)]
+        cells: HashMap<(i32, i32), u8>,
+        #[camo(skip)]
+        rows: HashMap<(i32, i32), u8>,
+    }
+
+    assert_eq!(
+        Definition::from(Foo::camo()).to_string(),
+        "
+        interface Foo {
+        \tnames: Record<string, number>;
+        \tids: Record<string, boolean>;
+        \tkinds: Record<Kind, number>;
+        \tcells: Record<string, string>;
+        }
+        "
+        .unindent()
+    );
+
+    assert_eq!(
+        diagnostics(&Foo::camo())
+            .into_iter()
+            .map(|diagnostic| diagnostic.message)
+            .collect::<Vec<_>>(),
+        Vec::from([String::from(
+            "`Foo`: the map keys in field `cells` cannot be serialized by `serde_json`, \
+            which requires keys that serialize as strings, numbers or booleans"
+        )])
+    );
+    assert!(diagnostics(&Kind::camo()).is_empty());
+    assert!(diagnostics(&Skipped::camo()).is_empty());
+}
+
+#[test]
+fn options_numeric_mapping() {
+    use camo::core::Direction;
+    use camo_typescript::Options;
+    use std::num::NonZeroU64;
+    use unindent::Unindent;
+
+    #[derive(Camo)]
+    #[serde(rename_all = "camelCase")]
+    struct Account {
+        id: u64,
+        parent_id: Option<NonZeroU64>,
+        balance: i128,
+        history: Vec<u64>,
+        login_count: u64,
+        age: u8,
+    }
+
+    let options = Options::new()
+        .builtin(camo::core::BuiltinType::U64, BuiltinType::BigInt)
+        .builtin(camo::core::BuiltinType::I128, BuiltinType::String)
+        .field(Account::identity(), "login_count", BuiltinType::Number);
+
+    assert_eq!(
+        Definition::from_container_with(Account::camo(), Direction::Serialize, &options)
+            .to_string(),
+        "
+        interface Account {
+        \tid: bigint;
+        \tparentId: 
+        \t| bigint
+        \t| null;
+        \tbalance: string;
+        \thistory: bigint[];
+        \tloginCount: number;
+        \tage: number;
+        }
+        "
+        .unindent()
+    );
+
+    assert_eq!(
+        Definition::from(Account::camo()).to_string(),
+        "
+        interface Account {
+        \tid: number;
+        \tparentId: 
+        \t| number
+        \t| null;
+        \tbalance: number;
+        \thistory: number[];
+        \tloginCount: number;
+        \tage: number;
+        }
+        "
+        .unindent()
+    );
+}
+
+#[test]
+fn options_field_override_identity() {
+    use camo::core::Direction;
+    use camo_typescript::Options;
+
+    mod a {
+        use camo::Camo;
+
+        #[derive(Camo)]
+        pub struct Foo {
+            pub id: u64,
+        }
+    }
+
+    mod b {
+        use camo::Camo;
+
+        #[derive(Camo)]
+        pub struct Foo {
+            pub id: u64,
+        }
+    }
+
+    let options = Options::new().field(a::Foo::identity(), "id", BuiltinType::String);
+
+    assert_eq!(
+        Definition::from_container_with(a::Foo::camo(), Direction::Serialize, &options).to_string(),
+        "export interface Foo {\n\tid: string;\n}\n"
+    );
+    assert_eq!(
+        Definition::from_container_with(b::Foo::camo(), Direction::Serialize, &options).to_string(),
+        "export interface Foo {\n\tid: number;\n}\n"
+    );
+}
+
+#[test]
+fn options_optional_style() {
+    use camo_typescript::{IntoDefinition, OptionStyle, Options};
+    use unindent::Unindent;
+
+    #[derive(Camo)]
+    struct Profile {
+        name: Option<String>,
+        nickname: Option<Option<String>>,
+    }
+
+    assert_eq!(
+        Profile::camo()
+            .into_definition_with(&Options::new().option_style(OptionStyle::Optional))
+            .to_string(),
+        "
+        interface Profile {
+        \tname?: string;
+        \tnickname?: 
+        \t| string
+        \t| null;
+        }
+        "
+        .unindent()
+    );
+
+    assert_eq!(
+        Profile::camo()
+            .into_definition_with(&Options::new().option_style(OptionStyle::Undefined))
+            .to_string(),
+        "
+        interface Profile {
+        \tname: 
+        \t| string
+        \t| undefined;
+        \tnickname: 
+        \t| string
+        \t| undefined;
+        }
+        "
+        .unindent()
+    );
+}
+
+#[test]
+fn options_optional_style_arrays() {
+    use camo_typescript::{IntoDefinition, OptionStyle, Options};
+    use unindent::Unindent;
+
+    #[derive(Camo)]
+    struct Scores {
+        values: Vec<Option<u32>>,
+    }
+
+    assert_eq!(
+        Scores::camo()
+            .into_definition_with(&Options::new().option_style(OptionStyle::Null))
+            .to_string(),
+        "
+        interface Scores {
+        \tvalues: (
+        \t| number
+        \t| null)[];
+        }
+        "
+        .unindent()
+    );
+
+    assert_eq!(
+        Scores::camo()
+            .into_definition_with(&Options::new().option_style(OptionStyle::Undefined))
+            .to_string(),
+        "
+        interface Scores {
+        \tvalues: (
+        \t| number
+        \t| undefined)[];
+        }
+        "
+        .unindent()
+    );
+}
+
+#[test]
+fn nested_unions() {
+    #[derive(Camo)]
+    struct Nested {
+        twice: Option<Option<u8>>,
+        pair: (Option<u32>, String),
+    }
+
+    assert_eq!(
+        Definition::from(Nested::camo()).to_string(),
+        "interface Nested {\n\ttwice: \n\t| number\n\t| null;\n\tpair: [(\n\t| number\n\t| null), string];\n}\n"
+    );
+
+    // Hand-written nested unions are flattened when rendered
+    let nested = Type::Union(UnionType {
+        variants: Vec::from([
+            Variant(Type::Union(UnionType {
+                variants: Vec::from([
+                    Variant(Type::Builtin(BuiltinType::Number)),
+                    Variant(Type::Builtin(BuiltinType::String)),
+                ]),
+            })),
+            Variant(Type::Builtin(BuiltinType::Null)),
+        ]),
+    });
+    assert_eq!(
+        Definition::Alias(TypeAlias {
+            export: false,
+            docs: Vec::new(),
+            name: String::from("Value"),
+            parameters: Vec::new(),
+            ty: nested,
+        })
+        .to_string(),
+        "type Value =\n\t| number\n\t| string\n\t| null;\n"
+    );
+}
+
+#[test]
+fn empty_unions() {
+    #[derive(Camo)]
+    enum Empty {}
+
+    assert_eq!(
+        Definition::from(Empty::camo()).to_string(),
+        "type Empty = never;\n"
+    );
+
+    #[derive(Camo)]
+    #[serde(tag = "type")]
+    enum Hidden {
+        #[camo(skip)]
+        Internal,
+    }
+
+    assert_eq!(
+        Definition::from(Hidden::camo()).to_string(),
+        "type Hidden = never;\n"
+    );
+}
+
+#[test]
+fn options_quote_escaping() {
+    use camo_typescript::{IntoDefinition, Options, Quote};
+
+    #[derive(Camo)]
+    #[serde(tag = "type")]
+    enum Quoted {
+        #[serde(rename = "it's")]
+        Single {
+            #[serde(rename = "say \"hi\"")]
+            value: u32,
+        },
+        #[serde(rename = "back\\slash")]
+        Backslash,
+    }
+
+    let options = Options::new().quote(Quote::Single);
+    assert_eq!(
+        Quoted::camo()
+            .into_definition_with(&options)
+            .display_with(&options)
+            .to_string(),
+        "type Quoted =\n\
+        \t| { type: 'it\\'s'; } & { 'say \"hi\"': number; }\n\
+        \t| { type: 'back\\\\slash'; };\n"
+    );
+
+    let options = Options::new().quote(Quote::Double);
+    assert_eq!(
+        Quoted::camo()
+            .into_definition_with(&options)
+            .display_with(&options)
+            .to_string(),
+        "type Quoted =\n\
+        \t| { type: \"it's\"; } & { \"say \\\"hi\\\"\": number; }\n\
+        \t| { type: \"back\\\\slash\"; };\n"
+    );
+}
+
+#[test]
+fn options_definition_style() {
+    use camo_typescript::{Export, IntoDefinition, Options, Quote};
+    use unindent::Unindent;
+
+    #[derive(Camo)]
+    pub struct Point {
+        x: i32,
+        #[serde(rename = "y-coordinate")]
+        y: i32,
+    }
+
+    #[derive(Camo)]
+    #[serde(tag = "type")]
+    enum Shape {
+        Circle { radius: f64 },
+        Empty,
+    }
+
+    let options = Options::new()
+        .interfaces(false)
+        .readonly(true)
+        .export(Export::None);
+    assert_eq!(
+        Point::camo().into_definition_with(&options).to_string(),
+        "type Point = { readonly x: number; readonly \"y-coordinate\": number; };\n"
+    );
+
+    let options = Options::new().export(Export::All);
+    assert_eq!(
+        Point::camo().into_definition_with(&options).to_string(),
+        "
+        export interface Point {
+        \tx: number;
+        \t\"y-coordinate\": number;
+        }
+        "
+        .unindent()
+    );
+
+    let options = Options::new().indent("    ").quote(Quote::Single);
+    let definition = Shape::camo().into_definition_with(&options);
+    assert_eq!(
+        definition.display_with(&options).to_string(),
+        "
+        type Shape =
+            | { type: 'Circle'; } & { radius: number; }
+            | { type: 'Empty'; };
+        "
+        .unindent()
+    );
+    assert_eq!(
+        definition.to_string(),
+        "
+        type Shape =
+        \t| { type: \"Circle\"; } & { radius: number; }
+        \t| { type: \"Empty\"; };
+        "
+        .unindent()
+    );
+}
+
+#[test]
+fn options_enum_style() {
+    use camo_typescript::{EnumStyle, IntoDefinition, Options};
+    use unindent::Unindent;
+
+    #[derive(Camo)]
+    #[serde(rename_all = "snake_case")]
+    pub enum BindingType {
+        Paperback,
+        Hardcover,
+        #[serde(rename = "ebook")]
+        Electronic,
+        #[serde(skip)]
+        Unbound,
+    }
+
+    let options = Options::new().enum_style(EnumStyle::Enum);
+    assert_eq!(
+        BindingType::camo()
+            .into_definition_with(&options)
+            .to_string(),
+        "
+        export enum BindingType {
+        \tPaperback = \"paperback\",
+        \tHardcover = \"hardcover\",
+        \tElectronic = \"ebook\",
+        }
+        "
+        .unindent()
+    );
+
+    let options = Options::new().enum_style(EnumStyle::ConstEnum);
+    assert_eq!(
+        BindingType::camo()
+            .into_definition_with(&options)
+            .to_string(),
+        "
+        export const enum BindingType {
+        \tPaperback = \"paperback\",
+        \tHardcover = \"hardcover\",
+        \tElectronic = \"ebook\",
+        }
+        "
+        .unindent()
+    );
+
+    let options = Options::new().enum_style(EnumStyle::UnionWithValues);
+    assert_eq!(
+        BindingType::camo()
+            .into_definition_with(&options)
+            .to_string(),
+        "
+        export type BindingType =
+        \t| \"paperback\"
+        \t| \"hardcover\"
+        \t| \"ebook\";
+        export const ALL_BINDING_TYPES = [\"paperback\", \"hardcover\", \"ebook\"] as const;
+        "
+        .unindent()
+    );
+}
+
+#[test]
+fn options_enum_style_repr() {
+    use camo_typescript::{EnumStyle, IntoDefinition, Options};
+    use unindent::Unindent;
+
+    #[derive(Camo)]
+    #[camo(repr)]
+    enum Status {
+        Active = 1,
+        Inactive,
+    }
+
+    #[derive(Camo)]
+    #[serde(tag = "type")]
+    enum Tagged {
+        A,
+        B,
+    }
+
+    let options = Options::new().enum_style(EnumStyle::Enum);
+    assert_eq!(
+        Status::camo().into_definition_with(&options).to_string(),
+        "
+        enum Status {
+        \tActive = 1,
+        \tInactive = 2,
+        }
+        "
+        .unindent()
+    );
+
+    let options = Options::new().enum_style(EnumStyle::UnionWithValues);
+    assert_eq!(
+        Status::camo().into_definition_with(&options).to_string(),
+        "
+        type Status =
+        \t| 1
+        \t| 2;
+        const ALL_STATUSES = [1, 2] as const;
+        "
+        .unindent()
+    );
+
+    // Internally tagged enums are serialized as objects
+    assert_eq!(
+        Tagged::camo().into_definition_with(&options).to_string(),
+        "
+        type Tagged =
+        \t| { type: \"A\"; }
+        \t| { type: \"B\"; };
+        "
+        .unindent()
+    );
+}
+
+#[test]
+fn generic_parameters() {
+    use unindent::Unindent;
+
+    #[derive(Camo)]
+    pub struct Page<T, U = Vec<T>, const N: usize = 4>
+    where
+        T: Clone,
+    {
+        items: U,
+        first: [T; N],
+    }
+
+    assert_eq!(
+        Definition::from(Page::<u8>::camo()).to_string(),
+        "
+        export interface Page<T, U = T[]> {
+        \titems: U;
+        \tfirst: T[];
+        }
+        "
+        .unindent()
+    );
+
+    assert_eq!(
+        Definition::from(<Result<u8, String> as Camo>::camo()).to_string(),
+        "
+        export type Result<T, E> =
+        \t| { Ok: T; }
+        \t| { Err: E; };
+        "
+        .unindent()
+    );
+}
+
+#[test]
+fn instantiated_generics() {
+    use unindent::Unindent;
+
+    #[derive(Camo)]
+    pub struct Page<T> {
+        items: Vec<T>,
+        next: Option<T>,
+    }
+
+    #[derive(Camo)]
+    pub struct User {
+        name: String,
+    }
+
+    let page = Page::<User>::camo();
+    let arguments = Vec::from([camo::core::Type::Path(camo::core::TypePath::from([
+        camo::core::PathSegment {
+            name: User::camo().item.name(),
+            arguments: Vec::new(),
+        },
+    ]))]);
+    let name = page.instance_name(&arguments);
+    assert_eq!(name, "PageUser");
+
+    let instance = page.instantiate(name, arguments).unwrap();
+    assert_eq!(
+        Definition::from(instance).to_string(),
+        "
+        export interface PageUser {
+        \titems: User[];
+        \tnext: 
+        \t| User
+        \t| null;
+        }
+        "
+        .unindent()
+    );
+}
+
+#[test]
+fn jsdoc_comments() {
+    use camo_typescript::{Export, IntoDefinition, Options};
+    use unindent::Unindent;
+
+    /// A user of the service.
+    ///
+    /// Create one with:
+    ///
+    /// ```
+    /// # use service::User;
+    /// let user = User::new("name");
+    /// ```
+    #[derive(Camo)]
+    #[allow(dead_code)]
+    pub struct User {
+        /// The name of the user.
+        name: String,
+        /// The age of the user, in years.
+        /// Ends with */ here.
+        #[deprecated = "use `birthday` instead"]
+        age: u32,
+        email: String,
+    }
+
+    #[allow(deprecated)]
+    let container = User::camo();
+    assert_eq!(
+        Definition::from(container.clone()).to_string(),
+        "
+        /**
+         * A user of the service.
+         *
+         * Create one with:
+         *
+         * ```rust
+         * let user = User::new(\"name\");
+         * ```
+         */
+        export interface User {
+        \t/** The name of the user. */
+        \tname: string;
+        \t/**
+        \t * The age of the user, in years.
+        \t * Ends with *\\/ here.
+        \t *
+        \t * @deprecated use `birthday` instead
+        \t */
+        \tage: number;
+        \temail: string;
+        }
+        "
+        .unindent()
+    );
+
+    // Object types are written on a single line, without documentation
+    let options = Options::new().interfaces(false).export(Export::None);
+    assert_eq!(
+        container.into_definition_with(&options).to_string(),
+        "
+        /**
+         * A user of the service.
+         *
+         * Create one with:
+         *
+         * ```rust
+         * let user = User::new(\"name\");
+         * ```
+         */
+        type User = { name: string; age: number; email: string; };
+        "
+        .unindent()
+    );
+
+    /// Where to go.
+    #[derive(Camo)]
+    #[deprecated(since = "1.0.0")]
+    #[allow(dead_code)]
+    enum Direction {
+        Up,
+        Down,
+    }
+
+    #[allow(deprecated)]
+    let container = Direction::camo();
+    assert_eq!(
+        Definition::from(container).to_string(),
+        "
+        /**
+         * Where to go.
+         *
+         * @deprecated Since 1.0.0.
+         */
+        type Direction =
+        \t| \"Up\"
+        \t| \"Down\";
+        "
+        .unindent()
+    );
+}
+
+#[test]
+fn camo_attributes() {
+    use camo_typescript::{Export, IntoDefinition, Options};
+    use unindent::Unindent;
+
+    pub struct Timestamp;
+
+    #[derive(Camo)]
+    #[serde(rename_all = "camelCase")]
+    #[camo(rename = "UserDto")]
+    pub struct User {
+        #[camo(rename = "displayName")]
+        user_name: String,
+        #[camo(skip)]
+        password_hash: String,
+        #[camo(foreign = "Temporal.Instant")]
+        created_at: Timestamp,
+    }
+
+    assert_eq!(
+        Definition::from(User::camo()).to_string(),
+        "
+        export interface UserDto {
+        \tdisplayName: string;
+        \tcreatedAt: Temporal.Instant;
+        }
+        "
+        .unindent()
+    );
+
+    #[derive(Camo, Serialize)]
+    #[camo(export = false)]
+    pub enum Status {
+        #[camo(rename = "ACTIVE")]
+        Active,
+        #[camo(skip)]
+        Internal,
+        Inactive,
+    }
+
+    assert_eq!(
+        Definition::from(Status::camo()).to_string(),
+        "
+        type Status =
+        \t| \"ACTIVE\"
+        \t| \"Inactive\";
+        "
+        .unindent()
+    );
+
+    #[derive(Camo)]
+    pub struct Entry(
+        String,
+        #[camo(skip)] u64,
+        #[camo(foreign = "Temporal.Instant")] Timestamp,
+    );
+
+    assert_eq!(
+        Definition::from(Entry::camo()).to_string(),
+        "export type Entry = [string, Temporal.Instant];\n"
+    );
+
+    #[derive(Camo)]
+    pub struct Hidden(#[camo(skip)] u64);
+
+    assert_eq!(
+        Definition::from(Hidden::camo()).to_string(),
+        "export type Hidden = [];\n"
+    );
+
+    #[derive(Camo)]
+    #[camo(export = true)]
+    struct Private {
+        value: u8,
+    }
+
+    let options = Options::new().export(Export::None);
+    assert_eq!(
+        Private::camo().into_definition_with(&options).to_string(),
+        "
+        export interface Private {
+        \tvalue: number;
+        }
+        "
+        .unindent()
+    );
+}
+
+#[test]
+fn camo_type_overrides() {
+    use unindent::Unindent;
+
+    pub trait Event {}
+
+    #[derive(Camo)]
+    pub struct Log {
+        #[camo(as = "std::collections::HashMap<String, String>")]
+        event: Box<dyn Event>,
+        #[camo(typescript = "Record<string, unknown>")]
+        payload: Box<dyn Event>,
+        #[camo(as = "Vec<u64>", typescript = "bigint[]")]
+        ids: Vec<u64>,
+    }
+
+    assert_eq!(
+        Definition::from(Log::camo()).to_string(),
+        "
+        export interface Log {
+        \tevent: Record<string, string>;
+        \tpayload: Record<string, unknown>;
+        \tids: bigint[];
+        }
+        "
+        .unindent()
+    );
+    #[derive(Camo)]
+    pub enum Message {
+        Event(#[camo(typescript = "Record<string, unknown>")] Box<dyn Event>),
+        Ids(#[camo(as = "Vec<u64>", typescript = "bigint[]")] Vec<u64>),
+    }
+
+    assert_eq!(
+        Definition::from(Message::camo()).to_string(),
+        "
+        export type Message =
+        \t| { Event: Record<string, unknown>; }
+        \t| { Ids: bigint[]; };
+        "
+        .unindent()
+    );
+
+    #[derive(Camo)]
+    pub struct Pair(
+        #[camo(typescript = "bigint")] u64,
+        #[camo(as = "String")] Box<dyn Event>,
+    );
+
+    assert_eq!(
+        Definition::from(Pair::camo()).to_string(),
+        "export type Pair = [bigint, string];\n"
+    );
+
+    // The type of a field with a TypeScript override is not described otherwise
+    let mut log = Log::camo();
+    if let camo::core::Item::Struct(s) = &mut log.item {
+        if let camo::core::StructContent::NamedFields(fields) = &mut s.content {
+            assert_eq!(fields[1].ty, camo::core::Type::Opaque);
+            fields[1].attributes.typescript = None;
+        }
+    }
+    assert_eq!(
+        Definition::from(log).to_string(),
+        "
+        export interface Log {
+        \tevent: Record<string, string>;
+        \tpayload: unknown;
+        \tids: bigint[];
+        }
+        "
+        .unindent()
+    );
+}
@@ -113,6 +113,7 @@
 //! | Feature      | Default | Description |
 //! | ------------ | ------- | ----------- |
 //! | `derive`     | Yes     | Enables the [`derive::Camo`] derive macro. |
+//! | `strict`     | No      | Makes the derive macro report `serde` attributes it does not model, as with `#[camo(strict)]`. |
 //! | `typescript` | No      | Enables the TypeScript backend, rooted in [`typescript::Definition`]. |
 //!
 //!
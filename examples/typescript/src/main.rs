@@ -1,6 +1,6 @@
 #![allow(dead_code)]
 
-use camo::{typescript::Definition, Camo};
+use camo::{core::TypeRegistry, typescript::Definition, Camo};
 use clap::Parser;
 use serde::Serialize;
 use std::fs::File;
@@ -31,21 +31,25 @@ enum Command {
 }
 
 fn main() -> std::result::Result<(), std::io::Error> {
-    let exports: &[Definition] = &[
-        Session::camo().into(),
-        User::camo().into(),
-        UserId::camo().into(),
-    ];
+    let mut registry = TypeRegistry::new();
+    registry.add::<Session>();
+
+    let exports: Vec<Definition> = registry
+        .containers()
+        .into_iter()
+        .cloned()
+        .map(Definition::from)
+        .collect();
 
     match Command::parse() {
         Command::Print => {
-            for ty in exports {
+            for ty in &exports {
                 println!("{}", ty);
             }
         }
         Command::Export { path } => {
             let mut file = File::create(path)?;
-            for ty in exports {
+            for ty in &exports {
                 writeln!(file, "{}", ty)?;
             }
         }